@@ -1,5 +1,5 @@
 use yew::{html, Html};
-use yew_mdc_widgets::{DataTable, TableCell};
+use yew_mdc_widgets::{console, Button, CsvOptions, DataTable, RowEvent, RowExpansion, TableCell};
 
 pub fn view() -> Html {
     html! {
@@ -64,7 +64,124 @@ pub fn view() -> Html {
                             TableCell::text("New filing flavor"),
                         ])
                 }</div>
+                <div>
+                    {
+                        Button::new().label("Download selected as CSV").on_click(|_| {
+                            let options = CsvOptions::default().selected_rows_only(true);
+                            if let Err(err) = DataTable::download_csv_existing("data-table-selection", "desserts.csv", options) {
+                                console::error!(err);
+                            }
+                        })
+                    }
+                    {
+                        Button::new().label("Copy as CSV").on_click(|_| {
+                            if let Err(err) = DataTable::copy_csv_existing("data-table-selection", CsvOptions::default()) {
+                                console::error!(err);
+                            }
+                        })
+                    }
+                </div>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Data Table with Editable Cells" }</h3>
+                <div>{
+                    DataTable::new("data-table-editable")
+                        .head(vec![
+                            TableCell::text("Dessert"),
+                            TableCell::num("Quantity"),
+                            TableCell::text("Comments"),
+                        ])
+                        .row(vec![
+                            TableCell::text("Frozen yogurt"),
+                            TableCell::editable_num("24"),
+                            TableCell::editable_text("Super tasty"),
+                        ])
+                        .row(vec![
+                            TableCell::text("Ice cream sandwich"),
+                            TableCell::editable_num("37"),
+                            TableCell::editable_text("I like ice cream more"),
+                        ])
+                        .column_validator(1, |value| {
+                            value
+                                .parse::<u32>()
+                                .map(|_| ())
+                                .map_err(|_| "Quantity must be a positive integer".to_string())
+                        })
+                        .on_cell_edit(|edit| console::log!(format!("{:?}", edit)))
+                }</div>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Data Table with Expandable Rows" }</h3>
+                <div>{
+                    DataTable::new("data-table-expandable")
+                        .row_selection(true)
+                        .expandable_rows(RowExpansion::Single)
+                        .head(vec![
+                            TableCell::text("Dessert"),
+                            TableCell::num("Carbs (g)"),
+                            TableCell::num("Protein (g)"),
+                        ])
+                        .row_with_detail(
+                            vec![TableCell::text("Frozen yogurt"), TableCell::num("24"), TableCell::num("4.0")],
+                            html! { <p>{ "A frozen dessert made with yogurt and sometimes other dairy products." }</p> },
+                        )
+                        .row_with_detail(
+                            vec![TableCell::text("Eclair"), TableCell::num("24"), TableCell::num("6.0")],
+                            html! { <p>{ "An oblong pastry made with choux dough filled with a cream." }</p> },
+                        )
+                        .on_row_expanded(|row_id| console::log!(format!("Expanded {}", row_id)))
+                }</div>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Data Table with Typed Records" }</h3>
+                <div>{
+                    DataTable::new("data-table-records")
+                        .head(vec![TableCell::text("Dessert"), TableCell::num("Calories")])
+                        .records(
+                            vec![Dessert { name: "Frozen yogurt", calories: 159 }, Dessert { name: "Eclair", calories: 262 }],
+                            |dessert| vec![TableCell::text(dessert.name), TableCell::num(dessert.calories.to_string())],
+                        )
+                        .on_row_click(|event: RowEvent| {
+                            if let Some(dessert) = event.record::<Dessert>() {
+                                console::log!(format!("Clicked row {}: {:?}", event.index, dessert));
+                            }
+                        })
+                        .on_row_activate(|event: RowEvent| console::log!(format!("Activated {}", event.row_id)))
+                }</div>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Data Table with Sticky Header and Fixed Column" }</h3>
+                <div>{
+                    (0..20).fold(
+                        DataTable::new("data-table-sticky")
+                            .row_selection(true)
+                            .sticky_header()
+                            .fixed_columns(1)
+                            .head((0..10).map(|column| {
+                                if column == 0 {
+                                    TableCell::text("Row")
+                                } else {
+                                    TableCell::num(format!("Column {}", column))
+                                }
+                            })),
+                        |table, row| {
+                            table.row((0..10).map(move |column| {
+                                if column == 0 {
+                                    TableCell::text(format!("Row {}", row))
+                                } else {
+                                    TableCell::num((row * column).to_string())
+                                }
+                            }))
+                        },
+                    )
+                }</div>
             </div>
         </div>
     }
 }
+
+#[derive(Debug)]
+struct Dessert {
+    name: &'static str,
+    calories: u32,
+}
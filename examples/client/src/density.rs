@@ -0,0 +1,50 @@
+use yew::{html, Html};
+use yew_mdc_widgets::{Button, Checkbox, Chip, ChipSet, Density, IconButton, MdcWidget, Radio, Tab, TabBar, TextField};
+
+fn controls(scale: i8) -> Html {
+    html! {
+        <>
+            <span class = "demo-item">
+                { TextField::outlined().id(format!("density-text-field-{}", -scale)).label("Outlined") }
+            </span>
+            <span class = "demo-item">
+                { TextField::filled().id(format!("density-filled-{}", -scale)).label("Filled") }
+            </span>
+            <span class = "demo-item">{ Button::outlined().label("Button") }</span>
+            <span class = "demo-item">{ IconButton::new().icon("favorite") }</span>
+            <span class = "demo-item">{ Checkbox::new() }</span>
+            <span class = "demo-item">{ Radio::new() }</span>
+            <span class = "demo-item">
+                { ChipSet::new().chip(Chip::simple().text("Chip")) }
+            </span>
+        </>
+    }
+}
+
+pub fn view() -> Html {
+    html! {
+        <div>
+            {
+                for (Density::MIN_SCALE..=Density::DEFAULT_SCALE).rev().map(|scale| html! {
+                    <div class = { Density::new(scale).classes() }>
+                        <h3 class = "mdc-typography--subtitle1">{ format!("Scale {}", scale) }</h3>
+                        { controls(scale) }
+                    </div>
+                })
+            }
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Per widget" }</h3>
+                <span class = "demo-item">
+                    {
+                        TabBar::new().id("tab-bar-dense")
+                            .density(-4)
+                            .tab(Tab::new().label("Tab One").tab_index(0))
+                            .tab(Tab::new().label("Tab Two"))
+                    }
+                </span>
+                <span class = "demo-item">{ Button::raised().label("Dense").density(-3) }</span>
+                <span class = "demo-item">{ Button::raised().label("Default") }</span>
+            </div>
+        </div>
+    }
+}
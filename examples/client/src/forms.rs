@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use yew::{function_component, html, use_state, Html};
+use yew_mdc_widgets::{
+    console, Button, Checkbox, Form, HelperText, IconButton, InputKind, LinearProgress, MdcWidget, Radio, Switch,
+    TextField, Validator,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Settings {
+    name: String,
+    age: Option<u32>,
+    newsletter: bool,
+    dark_mode: bool,
+    plan: Option<String>,
+    language: String,
+}
+
+pub fn view() -> Html {
+    let form = Form::new()
+        .id("settings-form")
+        .text_field(
+            "name",
+            TextField::outlined()
+                .id("settings-form-name")
+                .label("Name")
+                .required()
+                .helper_text(HelperText::new("Your name").validation_msg()),
+        )
+        .text_field(
+            "age",
+            TextField::outlined()
+                .id("settings-form-age")
+                .label("Age")
+                .input_kind(InputKind::Number)
+                .validator(Validator::range(1.0, 150.0))
+                .helper_text(HelperText::new("From 1 to 150").validation_msg()),
+        )
+        .child(html! { <div></div> })
+        .checkbox(
+            "newsletter",
+            Checkbox::new().id("settings-form-newsletter").label("Newsletter"),
+        )
+        .switch(
+            "dark_mode",
+            Switch::new().id("settings-form-dark-mode").label("Dark mode"),
+        )
+        .child(html! { <div></div> })
+        .radio(
+            "plan",
+            Radio::new().id("settings-form-plan-free").value("free").label("Free"),
+        )
+        .radio(
+            "plan",
+            Radio::new().id("settings-form-plan-pro").value("pro").label("Pro"),
+        )
+        .validator("plan", Validator::required().message("Choose the plan"))
+        .select("language", html! {
+            <select>
+                <option value = "en">{ "English" }</option>
+                <option value = "de">{ "Deutsch" }</option>
+            </select>
+        })
+        .child(html! { <div></div> })
+        .child(Button::raised().label("Save").attr("type", "submit"))
+        .on_submit(|settings: Settings| console::log!(format!("Submitted: {:?}", settings)))
+        .on_error(|err| console::error!(format!("Invalid settings: {}", err)));
+
+    html! {
+        <div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Settings form" }</h3>
+                { form }
+            </div>
+            <div>
+                <span class = "demo-item">
+                    {
+                        Button::new().label("Load settings").on_click(|_| {
+                            let settings = Settings {
+                                name: "Alice".into(),
+                                age: Some(30),
+                                newsletter: true,
+                                dark_mode: true,
+                                plan: Some("pro".into()),
+                                language: "de".into(),
+                            };
+                            if let Err(err) = Form::set_existing("settings-form", &settings) {
+                                console::error!(format!("Could not load settings: {}", err));
+                            }
+                        })
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        Button::new().label("Show state").on_click(|_| {
+                            console::log!(format!(
+                                "Values: {:?}, dirty: {:?}, touched: {:?}",
+                                Form::get_existing::<Settings>("settings-form"),
+                                Form::dirty_fields_existing("settings-form"),
+                                Form::touched_fields_existing("settings-form"),
+                            ));
+                        })
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Bound state" }</h3>
+                <BoundState />
+            </div>
+        </div>
+    }
+}
+
+#[function_component(BoundState)]
+fn bound_state() -> Html {
+    let name = use_state(String::new);
+    let agree = use_state(|| false);
+    let plan = use_state(|| "free".to_string());
+    let notifications = use_state(|| true);
+    let favorite = use_state(|| false);
+
+    let filled = [!name.is_empty(), *agree, *notifications, *favorite]
+        .iter()
+        .filter(|filled| **filled)
+        .count();
+
+    html! {
+        <div>
+            <div>
+                <span class = "demo-item">
+                    { TextField::outlined().id("bound-name").label("Name").bind(&name) }
+                </span>
+                <span class = "demo-item">
+                    {
+                        Button::new().label("Reset").on_click({
+                            let name = name.clone();
+                            move |_| name.set(String::new())
+                        })
+                    }
+                </span>
+            </div>
+            <div>
+                { Checkbox::new().id("bound-agree").bind(&agree).label("Agree") }
+                { Radio::new().id("bound-plan-free").name_of_set("bound-plan").bind(&plan, "free".into()).label("Free") }
+                { Radio::new().id("bound-plan-pro").name_of_set("bound-plan").bind(&plan, "pro".into()).label("Pro") }
+                { Switch::new().id("bound-notifications").bind(&notifications).label("Notifications") }
+                { IconButton::new().id("bound-favorite").toggle("favorite", "favorite_border").bind(&favorite) }
+            </div>
+            <div>
+                { LinearProgress::new().id("bound-progress").progress(filled as f32 / 4.0) }
+            </div>
+            <p class = "mdc-typography--body2">
+                {
+                    format!(
+                        "Name: {:?}, agree: {}, plan: {}, notifications: {}, favorite: {}",
+                        *name, *agree, *plan, *notifications, *favorite,
+                    )
+                }
+            </p>
+        </div>
+    }
+}
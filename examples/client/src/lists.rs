@@ -1,5 +1,9 @@
-use yew::{html, Html};
-use yew_mdc_widgets::{Checkbox, List, ListItem, MdcWidget, Radio};
+use yew::{function_component, html, use_state, Html};
+use yew_mdc_widgets::gloo::timers::callback::Timeout;
+use yew_mdc_widgets::{
+    console, Button, Checkbox, ItemHeight, List, ListGroup, ListGroupToggle, ListItem, ListSelection, MdcWidget, Radio,
+    TreeNode, TreeSelection, TreeToggle, TreeView,
+};
 
 pub fn view() -> Html {
     html! {
@@ -196,15 +200,257 @@ pub fn view() -> Html {
                 <h3 class = "mdc-typography--subtitle1">{ "List with activated item" }</h3>
                 <span class = "demo-item bordered-list demo-panel">
                     {
-                        List::ul().id("activated-list").single_selection().items(vec![
-                            ListItem::new().icon("inbox").text("Inbox"),
-                            ListItem::new().icon("star").text("Star").selected(true),
-                            ListItem::new().icon("send").text("Send"),
-                            ListItem::new().icon("drafts").text("Drafts"),
-                        ])
+                        List::ul()
+                            .id("activated-list")
+                            .single_selection()
+                            .vertical(true)
+                            .typeahead(true)
+                            .items(vec![
+                                ListItem::new().icon("inbox").text("Inbox"),
+                                ListItem::new().icon("star").text("Star").selected(true),
+                                ListItem::new().icon("send").text("Send"),
+                                ListItem::new().icon("drafts").text("Drafts"),
+                            ])
+                            .on_selection_change(|selection| console::log!(format!("Activated: {:?}", selection)))
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        Button::new().label("Disable drafts").on_click(|_| {
+                            List::set_enabled_existing("activated-list", 3, false);
+                            console::log!(format!(
+                                "Disabled: {:?}",
+                                List::primary_text_existing("activated-list", 3)
+                            ));
+                        })
+                    }
+                    {
+                        Button::new().label("Enable drafts").on_click(|_| {
+                            List::set_enabled_existing("activated-list", 3, true);
+                            console::log!(format!(
+                                "Enabled: {:?}",
+                                List::primary_text_existing("activated-list", 3)
+                            ));
+                        })
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Checkbox list" }</h3>
+                <span class = "demo-item bordered-list demo-panel">
+                    {
+                        List::ul()
+                            .id("checkbox-list")
+                            .checkbox_list()
+                            .attr("aria-label", "Toppings")
+                            .items(vec![
+                                ListItem::new()
+                                    .checkbox(Checkbox::simple().ripple(false).id("checkbox-list-cheese"))
+                                    .label("Cheese"),
+                                ListItem::new()
+                                    .checkbox(Checkbox::simple().ripple(false).id("checkbox-list-olives").checked(true))
+                                    .label("Olives"),
+                                ListItem::new()
+                                    .checkbox(Checkbox::simple().ripple(false).id("checkbox-list-onion"))
+                                    .label("Onion"),
+                            ])
+                            .on_selection_change(|selection| console::log!(format!("Checked: {:?}", selection)))
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Radio list" }</h3>
+                <span class = "demo-item bordered-list demo-panel">
+                    {
+                        List::ul()
+                            .id("radio-list")
+                            .radio_list()
+                            .attr("aria-label", "Size")
+                            .items(["Small", "Medium", "Large"].into_iter().enumerate().map(|(idx, size)| {
+                                ListItem::new()
+                                    .radio(
+                                        Radio::simple()
+                                            .ripple(false)
+                                            .id(format!("radio-list-{}", idx))
+                                            .name_of_set("radio-list-size")
+                                            .value(size)
+                                            .checked(idx == 1),
+                                    )
+                                    .label(size)
+                            }))
+                            .on_action(|_| {
+                                console::log!(format!("Selected: {:?}", List::selection_existing("radio-list")))
+                            })
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        Button::new()
+                            .label("Select large")
+                            .on_click(|_| List::set_selection_existing("radio-list", ListSelection::Single(2)))
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Sortable list" }</h3>
+                <span class = "demo-item bordered-list demo-panel">
+                    <Playlist />
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Virtual list" }</h3>
+                <span class = "demo-item bordered-list demo-panel">
+                    {
+                        List::ul()
+                            .id("virtual-list")
+                            .virtualized(10_000, ItemHeight::Fixed(48.0), |idx| {
+                                ListItem::new().text(format!("Item {}", idx + 1))
+                            })
+                            .viewport_height("240px")
+                            .typeahead(|idx| format!("Item {}", idx + 1))
+                            .on_action(|idx| console::log!(format!("Virtual list action: {}", idx)))
                     }
                 </span>
+                <span class = "demo-item bordered-list demo-panel">
+                    <InfiniteList />
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Tree view" }</h3>
+                <span class = "demo-item bordered-list demo-panel">
+                    <FileTree />
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Grouped list with sticky subheaders" }</h3>
+                <span class = "demo-item bordered-list demo-panel" style = "height: 300px; overflow-y: auto;">
+                    { contacts() }
+                </span>
             </div>
         </div>
     }
 }
+
+fn contacts() -> Html {
+    let mut names = vec![
+        "Alice", "Andrew", "Anna", "Bob", "Boris", "Carol", "Charlie", "Chloe", "Dave", "Diana", "Edward", "Emma",
+        "Frank", "Grace", "Henry", "Isabel", "Jack", "Julia",
+    ];
+    names.sort_unstable();
+
+    ListGroup::from_items(names.into_iter().map(|name| {
+        let letter = name.chars().next().unwrap_or_default();
+        (letter, ListItem::new().icon("person").text(name))
+    }))
+    .sticky_headers()
+    .collapsible()
+    .index_bar()
+    .on_toggle(|toggle: ListGroupToggle| console::log!(format!("Group {} expanded: {}", toggle.key, toggle.expanded)))
+    .into()
+}
+
+#[function_component(FileTree)]
+fn file_tree() -> Html {
+    let loaded = use_state(Vec::<String>::new);
+
+    let on_load = {
+        let loaded = loaded.clone();
+        move |key: String| {
+            // Imitates loading the folder content
+            let loaded = loaded.clone();
+            Timeout::new(500, move || {
+                let mut keys = (*loaded).clone();
+                keys.push(key);
+                loaded.set(keys);
+            })
+            .forget();
+        }
+    };
+
+    let downloads = if loaded.iter().any(|key| key == "downloads") {
+        TreeNode::new("downloads").children(vec![
+            TreeNode::new("downloads/setup.exe")
+                .icon("description")
+                .text("setup.exe"),
+            TreeNode::new("downloads/photo.png").icon("image").text("photo.png"),
+        ])
+    } else {
+        TreeNode::new("downloads").lazy()
+    };
+
+    html! {
+        {
+            TreeView::new()
+                .id("file-tree")
+                .multi_selection()
+                .nodes(vec![
+                    TreeNode::new("documents").icon("folder").text("Documents").expanded(true).children(vec![
+                        TreeNode::new("documents/work").icon("folder").text("Work").children(vec![
+                            TreeNode::new("documents/work/report.pdf").icon("description").text("report.pdf"),
+                        ]),
+                        TreeNode::new("documents/notes.txt").icon("description").text("notes.txt"),
+                    ]),
+                    downloads.icon("folder").text("Downloads"),
+                    TreeNode::new("music").icon("folder").text("Music").children(vec![
+                        TreeNode::new("music/song.mp3").icon("audiotrack").text("song.mp3"),
+                    ]),
+                ])
+                .on_toggle(|toggle: TreeToggle| console::log!(format!("Toggled: {:?}", toggle)))
+                .on_select(|selection: TreeSelection| console::log!(format!("Selected: {:?}", selection)))
+                .on_load(on_load)
+        }
+    }
+}
+
+#[function_component(InfiniteList)]
+fn infinite_list() -> Html {
+    const PAGE_SIZE: usize = 50;
+    let count = use_state(|| PAGE_SIZE);
+
+    let on_end_reached = {
+        let count = count.clone();
+        move |_| {
+            // Imitates loading the next page
+            let count = count.clone();
+            Timeout::new(500, move || count.set(*count + PAGE_SIZE)).forget();
+        }
+    };
+
+    html! {
+        {
+            List::ul()
+                .two_line()
+                .virtualized(*count, ItemHeight::Estimated(64.0), |idx| {
+                    ListItem::new().text(format!("Message {}", idx + 1)).text("Loaded on scroll")
+                })
+                .viewport_height("240px")
+                .on_end_reached(on_end_reached)
+        }
+    }
+}
+
+#[function_component(Playlist)]
+fn playlist() -> Html {
+    let tracks = use_state(|| vec!["Intro", "Overture", "Nocturne", "Waltz", "Finale"]);
+
+    let on_reorder = {
+        let tracks = tracks.clone();
+        move |(from, to): (usize, usize)| {
+            let mut reordered = (*tracks).clone();
+            let track = reordered.remove(from);
+            reordered.insert(to, track);
+            tracks.set(reordered);
+        }
+    };
+
+    html! {
+        {
+            List::ul()
+                .id("sortable-list")
+                .single_selection()
+                .sortable()
+                .items(tracks.iter().map(|track| ListItem::new().icon("music_note").text(*track).drag_handle()))
+                .on_reorder(on_reorder)
+        }
+    }
+}
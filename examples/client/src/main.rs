@@ -10,8 +10,10 @@ mod cards;
 mod checkboxes;
 mod chips;
 mod data_tables;
+mod density;
 mod dialog;
 mod fabs;
+mod forms;
 mod icon_buttons;
 mod linear_progress;
 mod lists;
@@ -44,12 +46,14 @@ impl Component for Root {
             ListItem::link("#snackbars").text("Snackbars"),
             ListItem::link("#linear_progress").text("LinearProgress"),
             ListItem::link("#text_fields").text("Text fields"),
+            ListItem::link("#forms").text("Forms"),
             ListItem::link("#lists").text("Lists"),
             ListItem::link("#menu").text("Menu"),
             ListItem::link("#data_tables").text("Data tables"),
             ListItem::link("#dialog").text("Dialog"),
             ListItem::link("#tabs").text("Tabs"),
             ListItem::link("#cards").text("Cards"),
+            ListItem::link("#density").text("Density"),
         ];
 
         let drawer = Drawer::new()
@@ -122,6 +126,9 @@ impl Component for Root {
                             <h2 class = "demo-title mdc-typography--headline6"><a href = "#" name = "text_fields"></a>{ "Text fields" }</h2>
                             { text_fields::view() }
 
+                            <h2 class = "demo-title mdc-typography--headline6"><a href = "#" name = "forms"></a>{ "Forms" }</h2>
+                            { forms::view() }
+
                             <h2 class = "demo-title mdc-typography--headline6"><a href = "#" name = "lists"></a>{ "Lists" }</h2>
                             { lists::view() }
 
@@ -139,6 +146,9 @@ impl Component for Root {
 
                             <h2 class = "demo-title mdc-typography--headline6"><a href = "#" name = "cards"></a>{ "Cards" }</h2>
                             { cards::view() }
+
+                            <h2 class = "demo-title mdc-typography--headline6"><a href = "#" name = "density"></a>{ "Density" }</h2>
+                            { density::view() }
                         </div>
                     </div>
                 </div>
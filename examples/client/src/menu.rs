@@ -1,5 +1,7 @@
-use yew::{html, Html};
-use yew_mdc_widgets::{Button, ListItem, MdcWidget, Menu};
+use yew::{html, Html, MouseEvent};
+use yew_mdc_widgets::{
+    console, AnchorMargin, Button, Checkbox, Corner, ListItem, MdcWidget, Menu, MenuSelection, MenuSurface, TextField,
+};
 
 pub fn view() -> Html {
     html! {
@@ -23,6 +25,164 @@ pub fn view() -> Html {
                     </div>
                 </span>
             </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Selection groups" }</h3>
+                <span class = "demo-item demo-list">
+                    <div class = { Menu::ANCHOR_CLASS }>
+                        {
+                            Button::new()
+                                .label("Open Menu")
+                                .on_click(|_| Menu::open_existing("selection-menu"))
+                        }
+                        {
+                            Menu::new()
+                                .id("selection-menu")
+                                .selection_group(
+                                    vec![
+                                        ListItem::new().text("Single").value("single"),
+                                        ListItem::new().text("1.15").value("1.15"),
+                                        ListItem::new().text("Double").value("double"),
+                                    ],
+                                    Some(0),
+                                )
+                                .divider()
+                                .items(vec![
+                                    ListItem::new().text("Add space before paragraph").value("space-before"),
+                                    ListItem::new().text("Add space after paragraph").value("space-after"),
+                                ])
+                                .on_selected(|selection: MenuSelection| {
+                                    console::log!(format!("Selected: {:?}", selection))
+                                })
+                        }
+                    </div>
+                </span>
+                <span class = "demo-item">
+                    {
+                        Button::new()
+                            .label("Select double")
+                            .on_click(|_| Menu::set_selected_index("selection-menu", 2))
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Submenus" }</h3>
+                <span class = "demo-item demo-list">
+                    <div class = { Menu::ANCHOR_CLASS }>
+                        {
+                            Button::new()
+                                .label("Open Menu")
+                                .on_click(|_| Menu::open_existing("nested-menu"))
+                        }
+                        {
+                            Menu::new()
+                                .id("nested-menu")
+                                .items(vec![
+                                    ListItem::new().text("New").value("new"),
+                                    ListItem::new().text("Open").value("open"),
+                                ])
+                                .submenu(
+                                    ListItem::new().text("Share"),
+                                    Menu::new()
+                                        .id("nested-menu-share")
+                                        .items(vec![
+                                            ListItem::new().text("Email").value("email"),
+                                            ListItem::new().text("Copy link").value("link"),
+                                        ])
+                                        .submenu(
+                                            ListItem::new().text("Social"),
+                                            Menu::new().id("nested-menu-social").items(vec![
+                                                ListItem::new().text("Mastodon").value("mastodon"),
+                                                ListItem::new().text("Matrix").value("matrix"),
+                                            ]),
+                                        )
+                                        .on_selected(|selection: MenuSelection| {
+                                            console::log!(format!("Share: {:?}", selection.value))
+                                        }),
+                                )
+                                .on_selected(|selection: MenuSelection| {
+                                    console::log!(format!("Selected: {:?}", selection.value))
+                                })
+                        }
+                    </div>
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Context menu" }</h3>
+                <span class = "demo-item">
+                    <div class = "mdc-card mdc-card--outlined" style = "width: 240px; height: 120px; padding: 16px;"
+                            oncontextmenu = { Menu::open_on_context_menu("context-menu") }>
+                        { "Right-click here" }
+                    </div>
+                </span>
+                <span class = "demo-item">
+                    { Button::outlined().label("Or right-click me").context_menu("context-menu") }
+                </span>
+                {
+                    Menu::new()
+                        .id("context-menu")
+                        .items(vec![
+                            ListItem::new().text("Cut").value("cut"),
+                            ListItem::new().text("Copy").value("copy"),
+                            ListItem::new().text("Paste").value("paste"),
+                        ])
+                        .on_selected(|selection: MenuSelection| {
+                            console::log!(format!("Context menu action: {:?}", selection.value))
+                        })
+                }
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Menu surface" }</h3>
+                <span class = "demo-item">
+                    {
+                        MenuSurface::new()
+                            .id("filter-surface")
+                            .anchor(
+                                Button::outlined()
+                                    .label("Filters")
+                                    .on_click(|_| MenuSurface::open_existing("filter-surface")),
+                            )
+                            .anchor_corner(Corner::BottomStart)
+                            .anchor_margin(AnchorMargin { top: 4.0, ..Default::default() })
+                            .content(html! {
+                                <div style = "padding: 16px;">
+                                    <div>{ Checkbox::new().id("filter-surface-active").label("Active only") }</div>
+                                    <div>
+                                        { TextField::outlined().id("filter-surface-name").label("Name contains") }
+                                    </div>
+                                    <div>
+                                        {
+                                            Button::new()
+                                                .label("Apply")
+                                                .on_click(|_| MenuSurface::close_existing("filter-surface"))
+                                        }
+                                    </div>
+                                </div>
+                            })
+                            .on_opened(|_| console::log!("Filters opened"))
+                            .on_closed(|_| console::log!("Filters closed"))
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        Button::outlined().label("Open at position").on_click(|event: MouseEvent| {
+                            MenuSurface::open_at_existing(
+                                "position-surface",
+                                event.client_x() as f64,
+                                event.client_y() as f64,
+                            )
+                        })
+                    }
+                    {
+                        MenuSurface::new()
+                            .id("position-surface")
+                            .fixed()
+                            .quick_open()
+                            .content(html! {
+                                <div style = "padding: 16px;">{ "Opened at the click position" }</div>
+                            })
+                    }
+                </span>
+            </div>
         </div>
     }
 }
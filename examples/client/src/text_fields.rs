@@ -1,5 +1,38 @@
 use yew::{html, Html};
-use yew_mdc_widgets::{HelperText, MdcWidget, TextField};
+use yew_mdc_widgets::dom::existing;
+use yew_mdc_widgets::wasm_bindgen_futures::JsFuture;
+use yew_mdc_widgets::{
+    console, js_sys, Autocomplete, HelperText, InputKind, Mask, MdcWidget, TextField, TextFieldStyle, ValidateOn,
+    Validator,
+};
+
+const FRUITS: [&str; 10] = [
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Lemon",
+    "Mango",
+    "Orange",
+    "Peach",
+];
+
+/// Imitates a request to the server, which searches the fruits.
+async fn search_fruits(query: String) -> Vec<String> {
+    let delay = js_sys::Promise::new(&mut |resolve, _| {
+        let _ = existing::window().set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 300);
+    });
+    let _ = JsFuture::from(delay).await;
+
+    let query = query.to_lowercase();
+    FRUITS
+        .iter()
+        .filter(|fruit| fruit.to_lowercase().starts_with(&query))
+        .map(ToString::to_string)
+        .collect()
+}
 
 pub fn view() -> Html {
     html! {
@@ -83,6 +116,24 @@ pub fn view() -> Html {
                             .helper_text(HelperText::new("Helper text"))
                     }
                 </span>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-soft-charcounter")
+                            .label("Soft limit")
+                            .char_counter_soft(10)
+                            .helper_text(HelperText::new("Up to 10 characters recommended").persistent())
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-count-only")
+                            .label("Count only")
+                            .value("Pre-filled value")
+                            .char_count()
+                    }
+                </span>
             </div>
             <div>
                 <h3 class = "mdc-typography--subtitle1">{ "With icon" }</h3>
@@ -93,6 +144,18 @@ pub fn view() -> Html {
                     { TextField::outlined().id("text-field-outlined-icon").label("Outlined text field").leading_icon("event").trailing_icon("delete") }
                 </span>
             </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Prefix and suffix" }</h3>
+                <span class = "demo-item">
+                    { TextField::filled().id("text-field-filled-prefix").label("Price").prefix("$").suffix(".00") }
+                </span>
+                <span class = "demo-item">
+                    { TextField::outlined().id("text-field-outlined-suffix").label("Weight").suffix("kg") }
+                </span>
+                <span class = "demo-item">
+                    { TextField::outlined().id("text-field-outlined-domain").label("Domain").value("example").suffix(".com") }
+                </span>
+            </div>
             <div>
                 <h3 class = "mdc-typography--subtitle1">{ "Disabled" }</h3>
                 <span class = "demo-item">
@@ -105,6 +168,139 @@ pub fn view() -> Html {
                     { TextField::outlined().id("text-field-prefilled-disabled").label("Outlined disabled text field").value("Pre-filled value").disabled() }
                 </span>
             </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Textarea" }</h3>
+                <span class = "demo-item">
+                    {
+                        TextField::textarea(TextFieldStyle::Filled)
+                            .id("text-field-filled-textarea")
+                            .label("Filled textarea")
+                            .auto_grow(2, 6)
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        TextField::textarea(TextFieldStyle::Outlined)
+                            .id("text-field-outlined-textarea")
+                            .label("Outlined textarea")
+                            .char_counter(140)
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Input kinds" }</h3>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-number")
+                            .label("Quantity")
+                            .input_kind(InputKind::Number)
+                            .min(0)
+                            .max(100)
+                            .step(0.5)
+                            .on_input(|_| console::log!(format!("{:?}", TextField::get_number("text-field-number"))))
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-date")
+                            .label("Date")
+                            .input_kind(InputKind::Date)
+                            .value("2024-01-01")
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-password")
+                            .label("Password")
+                            .autocomplete("current-password")
+                            .password_toggle()
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Validation" }</h3>
+                <span class = "demo-item">
+                    {
+                        TextField::filled()
+                            .id("text-field-validation-email")
+                            .label("Email")
+                            .input_kind(InputKind::Email)
+                            .required()
+                            .validator(Validator::email())
+                            .helper_text(HelperText::new("Your work email").persistent())
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-validation-username")
+                            .label("Username")
+                            .validate_on(ValidateOn::Input)
+                            .validator(Validator::min_length(3))
+                            .validator(Validator::max_length(16))
+                            .validator(Validator::pattern("[a-z0-9_]*").message("Only lowercase letters, digits and _"))
+                            .helper_text(HelperText::new("3 to 16 characters"))
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Masks" }</h3>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-mask-phone")
+                            .label("Phone")
+                            .input_kind(InputKind::Tel)
+                            .mask("+9 (999) 999-99-99")
+                            .on_input(|_| {
+                                console::log!(format!("Raw phone: {}", TextField::get_raw_value("text-field-mask-phone")))
+                            })
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-mask-card")
+                            .label("Card number")
+                            .input_mode("numeric")
+                            .mask(Mask::card_number())
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        TextField::outlined()
+                            .id("text-field-mask-iban")
+                            .label("IBAN")
+                            .value("de89370400440532013000")
+                            .mask(Mask::iban())
+                    }
+                </span>
+            </div>
+            <div>
+                <h3 class = "mdc-typography--subtitle1">{ "Autocomplete" }</h3>
+                <span class = "demo-item">
+                    {
+                        Autocomplete::new(TextField::outlined().id("text-field-autocomplete").label("Fruit"))
+                            .options(FRUITS)
+                            .on_select(|fruit: &str| console::log!(format!("Selected: {}", fruit)))
+                    }
+                </span>
+                <span class = "demo-item">
+                    {
+                        Autocomplete::new(
+                            TextField::outlined()
+                                .id("text-field-autocomplete-async")
+                                .label("Fruit (async)")
+                                .helper_text(HelperText::new("Searched by the first letters").persistent()),
+                        )
+                        .source_async(search_fruits)
+                        .on_select(|fruit: String| console::log!(format!("Selected: {}", fruit)))
+                    }
+                </span>
+            </div>
         </div>
     }
 }
@@ -0,0 +1,445 @@
+use std::fmt::{self, Display};
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::rc::Rc;
+
+use yew::html::{oninput, onkeydown, onmousedown};
+use yew::{
+    function_component, html, use_effect_with, use_mut_ref, use_state, Callback, Html, InputEvent, KeyboardEvent,
+    MouseEvent, Properties, ToHtml,
+};
+
+use crate::dom::{self, JsCast};
+use crate::menu_surface::{self, Corner};
+use crate::utils::{mdc_object, VTagExt};
+use crate::web_sys::{Element, HtmlInputElement};
+use crate::{text_field, List, ListItem, MdcWidget, MenuSurface, TextField};
+
+pub type FilterFn<T> = dyn Fn(&T, &str) -> bool;
+
+pub type AsyncSourceFn<T> = dyn Fn(String) -> Pin<Box<dyn Future<Output = Vec<T>>>>;
+
+#[derive(Clone)]
+enum OptionSource<T> {
+    Static(Rc<Vec<T>>),
+    Async(Rc<AsyncSourceFn<T>>),
+}
+
+impl<T> PartialEq for OptionSource<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Static(this), Self::Static(other)) => Rc::ptr_eq(this, other),
+            (Self::Async(this), Self::Async(other)) => Rc::ptr_eq(this, other),
+            _ => false,
+        }
+    }
+}
+
+/// Type-ahead text field, which shows the options matching the input in a dropdown.
+///
+/// The options are taken either from a static list filtered by the input value or from an async
+/// source, which gets the input value and returns the matching options. The options are rendered
+/// with their `Display` implementation, as the list items of the menu surface anchored to the
+/// text field.
+#[derive(Clone)]
+pub struct Autocomplete<T> {
+    html: Html,
+    text_field: TextField,
+    source: OptionSource<T>,
+    filter: Rc<FilterFn<T>>,
+    on_select: Option<Callback<T>>,
+}
+
+impl<T: Clone + Display + 'static> Autocomplete<T> {
+    /// Creates the autocomplete for the text field, which must have ID.
+    pub fn new(text_field: TextField) -> Self {
+        Self {
+            html: html! { <div class = { MenuSurface::ANCHOR_CLASS } style = "display: inline-block;"></div> },
+            text_field,
+            source: OptionSource::Static(Rc::new(Vec::new())),
+            filter: Rc::new(|option: &T, query: &str| {
+                option.to_string().to_lowercase().contains(&query.to_lowercase())
+            }),
+            on_select: None,
+        }
+    }
+
+    /// Sets the static options, which are filtered by the input value.
+    pub fn options(mut self, options: impl IntoIterator<Item = T>) -> Self {
+        self.source = OptionSource::Static(Rc::new(options.into_iter().collect()));
+        self
+    }
+
+    /// Sets the filter of the static options. By default, the options containing the input value
+    /// case-insensitively are shown.
+    pub fn filter(mut self, filter: impl Fn(&T, &str) -> bool + 'static) -> Self {
+        self.filter = Rc::new(filter);
+        self
+    }
+
+    /// Sets the async source of the options, which gets the input value and returns the options to
+    /// show. Results of the outdated requests are ignored.
+    pub fn source_async<F, Fut>(mut self, source: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Vec<T>> + 'static,
+    {
+        self.source = OptionSource::Async(Rc::new(move |query| Box::pin(source(query))));
+        self
+    }
+
+    /// Sets the callback, which is called with the option chosen by click or Enter.
+    pub fn on_select(mut self, callback: impl Into<Callback<T>>) -> Self {
+        self.on_select = Some(callback.into());
+        self
+    }
+
+    /// Closes the dropdown of the existing autocomplete by its text field ID.
+    pub fn close_existing(text_field_id: impl AsRef<str>) {
+        close_surface(&Ids::new(text_field_id.as_ref()).surface);
+    }
+}
+
+impl<T> PartialEq for Autocomplete<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.html == other.html
+            && *self.text_field == *other.text_field
+            && self.source == other.source
+            && Rc::ptr_eq(&self.filter, &other.filter)
+            && self.on_select == other.on_select
+    }
+}
+
+/// The IDs of the autocomplete elements, which are derived from the text field ID.
+struct Ids {
+    text_field: String,
+    surface: String,
+    listbox: String,
+}
+
+impl Ids {
+    fn new(text_field_id: &str) -> Self {
+        Self {
+            text_field: text_field_id.to_string(),
+            surface: format!("{}-surface", text_field_id),
+            listbox: format!("{}-listbox", text_field_id),
+        }
+    }
+
+    fn option(&self, idx: usize) -> String {
+        format!("{}-option-{}", self.text_field, idx)
+    }
+}
+
+#[derive(Properties)]
+struct ViewProps<T> {
+    autocomplete: Autocomplete<T>,
+}
+
+impl<T> PartialEq for ViewProps<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.autocomplete == other.autocomplete
+    }
+}
+
+#[function_component(AutocompleteView)]
+fn autocomplete_view<T>(props: &ViewProps<T>) -> Html
+where
+    T: Clone + Display + 'static,
+{
+    let autocomplete = &props.autocomplete;
+    let ids = Rc::new(Ids::new(&autocomplete.text_field.root_id()));
+    let matched = use_state(|| Rc::new(Vec::<T>::new()));
+    let active = use_state(|| None::<usize>);
+    let is_open = use_state(|| false);
+    let request = use_mut_ref(|| 0_u64);
+
+    {
+        let surface_id = ids.surface.clone();
+        use_effect_with(*is_open, move |is_open| {
+            // The options are rendered before the surface is opened, so it is positioned by their size
+            if *is_open {
+                MenuSurface::open_existing(&surface_id);
+            } else {
+                close_surface(&surface_id);
+            }
+        });
+    }
+    {
+        let active_id = active.map(|idx| ids.option(idx));
+        use_effect_with(active_id, |active_id| {
+            if let Some(option) = active_id
+                .as_ref()
+                .and_then(|id| dom::existing::document().get_element_by_id(id))
+            {
+                option.scroll_into_view_with_bool(false);
+            }
+        });
+    }
+
+    let close = {
+        let active = active.clone();
+        let is_open = is_open.clone();
+        move || {
+            active.set(None);
+            is_open.set(false);
+        }
+    };
+
+    let show_options = {
+        let show = {
+            let matched = matched.clone();
+            let active = active.clone();
+            let is_open = is_open.clone();
+            move |options: Vec<T>| {
+                active.set(None);
+                is_open.set(!options.is_empty());
+                matched.set(Rc::new(options));
+            }
+        };
+        let source = autocomplete.source.clone();
+        let filter = autocomplete.filter.clone();
+        Rc::new(move |query: String| match &source {
+            OptionSource::Static(options) => {
+                show(
+                    options
+                        .iter()
+                        .filter(|option| filter(option, &query))
+                        .cloned()
+                        .collect(),
+                );
+            },
+            OptionSource::Async(source) => {
+                let future = source(query);
+                let current = request.borrow().wrapping_add(1);
+                *request.borrow_mut() = current;
+
+                let request = request.clone();
+                let show = show.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let options = future.await;
+                    if *request.borrow() == current {
+                        show(options);
+                    }
+                });
+            },
+        })
+    };
+
+    let select = {
+        let matched = matched.clone();
+        let on_select = autocomplete.on_select.clone();
+        let text_field_id = ids.text_field.clone();
+        let close = close.clone();
+        Rc::new(move |idx: usize| {
+            if let Some(option) = matched.get(idx).cloned() {
+                set_input_value(&text_field_id, &option.to_string());
+                close();
+                if let Some(on_select) = &on_select {
+                    on_select.emit(option);
+                }
+            }
+        })
+    };
+
+    let mut text_field = autocomplete.text_field.clone();
+    if let Some(input) = text_field.input_tag_mut() {
+        input.set_attr("role", "combobox");
+        input.set_attr("aria-autocomplete", "list");
+        input.set_attr("aria-expanded", is_open.to_string());
+        let controls = match input.attr("aria-controls") {
+            Some(controls) => format!("{} {}", controls, ids.listbox),
+            None => ids.listbox.clone(),
+        };
+        input.set_attr("aria-controls", controls);
+        input.set_attr("autocomplete", "off");
+        if let Some(idx) = *active {
+            input.set_attr("aria-activedescendant", ids.option(idx));
+        }
+
+        let show = show_options.clone();
+        input.add_listener(Rc::new(oninput::Wrapper::new(Callback::from(
+            move |event: InputEvent| {
+                if let Some(input) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                {
+                    show(input.value());
+                }
+            },
+        ))));
+
+        let count = matched.len();
+        let select = select.clone();
+        let active = active.clone();
+        let is_open = *is_open;
+        input.add_listener(Rc::new(onkeydown::Wrapper::new(Callback::from(
+            move |event: KeyboardEvent| {
+                let Some(input) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                else {
+                    return;
+                };
+                match event.key().as_str() {
+                    "ArrowDown" | "ArrowUp" if !is_open => {
+                        event.prevent_default();
+                        show_options(input.value());
+                    },
+                    "ArrowDown" => {
+                        event.prevent_default();
+                        active.set(next_active(*active, count, 1));
+                    },
+                    "ArrowUp" => {
+                        event.prevent_default();
+                        active.set(next_active(*active, count, -1));
+                    },
+                    "Enter" if is_open => {
+                        if let Some(idx) = *active {
+                            event.prevent_default();
+                            select(idx);
+                        }
+                    },
+                    "Escape" if is_open => {
+                        event.prevent_default();
+                        close();
+                    },
+                    "Tab" => close(),
+                    _ => (),
+                }
+            },
+        ))));
+    }
+
+    let items = matched.iter().enumerate().map(|(idx, option)| {
+        let is_active = *active == Some(idx);
+        let select = select.clone();
+        let item = ListItem::new()
+            .id(ids.option(idx))
+            .attr("role", "option")
+            .attr("aria-selected", is_active.to_string())
+            .text(option.to_string())
+            .on_click(move |_| select(idx));
+        if is_active {
+            item.class(ListItem::ACTIVATED_CLASS)
+        } else {
+            item
+        }
+    });
+    let list = List::simple_ul()
+        .id(ids.listbox.clone())
+        .attr("role", "listbox")
+        .items(items)
+        // Keeps the focus in the input while clicking the options
+        .listener(Rc::new(onmousedown::Wrapper::new(Callback::from(
+            |event: MouseEvent| event.prevent_default(),
+        ))));
+
+    let surface = {
+        let active = active.clone();
+        let is_open = is_open.clone();
+        MenuSurface::new()
+            .id(ids.surface.clone())
+            .fullwidth()
+            .anchor_corner(Corner::BottomStart)
+            .content(list)
+            // The surface is closed by MDC as well, e.g. on a click outside
+            .on_closed(move |_| {
+                active.set(None);
+                is_open.set(false);
+            })
+    };
+
+    let mut html = autocomplete.html.clone();
+    if let Some(root) = html.root_tag_mut() {
+        root.add_child(text_field.into());
+        root.add_child(surface.into());
+    }
+    html
+}
+
+fn next_active(active: Option<usize>, count: usize, step: isize) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    let count = count as isize;
+    let next = match active {
+        Some(idx) => (idx as isize + step).rem_euclid(count),
+        None if step > 0 => 0,
+        None => count - 1,
+    };
+    Some(next as usize)
+}
+
+fn close_surface(surface_id: &str) {
+    let surface = dom::existing::document()
+        .get_element_by_id(surface_id)
+        .and_then(|surface| mdc_object(&surface, menu_surface::mdc::TYPE_NAME))
+        .map(JsCast::unchecked_into::<menu_surface::mdc::MenuSurface>);
+    if let Some(surface) = surface.filter(|surface| surface.is_open()) {
+        surface.close(true);
+    }
+}
+
+fn set_input_value(text_field_id: &str, value: &str) {
+    let root = dom::existing::get_element_by_id::<Element>(text_field_id);
+    match mdc_object(&root, text_field::mdc::TYPE_NAME) {
+        Some(text_field) => text_field
+            .unchecked_into::<text_field::mdc::TextField>()
+            .set_value(value),
+        None => text_field::set_input_value(&root, value),
+    }
+}
+
+impl<T> fmt::Debug for Autocomplete<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Autocomplete))
+            .field("html", &self.html)
+            .field("text_field", &self.text_field)
+            .field("on_select", &self.on_select)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + Display + 'static> MdcWidget for Autocomplete<T> {
+    const NAME: &'static str = stringify!(Autocomplete);
+
+    fn html(&self) -> &Html {
+        &self.html
+    }
+
+    fn html_mut(&mut self) -> &mut Html {
+        &mut self.html
+    }
+}
+
+impl<T> Deref for Autocomplete<T> {
+    type Target = Html;
+
+    fn deref(&self) -> &Self::Target {
+        &self.html
+    }
+}
+
+impl<T> DerefMut for Autocomplete<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.html
+    }
+}
+
+impl<T: Clone + Display + 'static> From<Autocomplete<T>> for Html {
+    fn from(autocomplete: Autocomplete<T>) -> Self {
+        html! { <AutocompleteView<T> autocomplete = { autocomplete } /> }
+    }
+}
+
+impl<T: Clone + Display + 'static> ToHtml for Autocomplete<T> {
+    fn to_html(&self) -> Html {
+        self.clone().into()
+    }
+
+    fn into_html(self) -> Html {
+        self.into()
+    }
+}
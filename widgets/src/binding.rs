@@ -0,0 +1,109 @@
+use gloo::timers::callback::Timeout;
+use yew::virtual_dom::VTag;
+use yew::{Callback, Reducible, UseReducerHandle, UseStateHandle};
+
+use crate::web_sys::Element;
+use crate::{auto_init, AUTO_INIT_ATTR};
+
+/// Connects a widget to the model: the widget shows the model value and emits the changes made by
+/// the user to `on_change`.
+///
+/// The binding is created from a `UseStateHandle`, from a `UseReducerHandle` with
+/// [`Binding::from_reducer`] or from the value and callback pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Binding<T> {
+    value: T,
+    on_change: Callback<T>,
+}
+
+impl<T: 'static> Binding<T> {
+    pub fn new(value: T, on_change: impl Into<Callback<T>>) -> Self {
+        Self {
+            value,
+            on_change: on_change.into(),
+        }
+    }
+
+    /// Binds the part of the reducer state, which is taken by `get`. The changes are dispatched
+    /// as the actions made by `action`.
+    pub fn from_reducer<R>(
+        handle: &UseReducerHandle<R>,
+        get: impl FnOnce(&R) -> T,
+        action: impl Fn(T) -> R::Action + 'static,
+    ) -> Self
+    where
+        R: Reducible + 'static,
+    {
+        let value = get(handle);
+        let handle = handle.clone();
+        Self::new(value, move |value| handle.dispatch(action(value)))
+    }
+
+    /// Converts the binding to the binding of another type, like a number model to a text field.
+    pub fn map<U: 'static>(self, to: impl FnOnce(T) -> U, from: impl Fn(U) -> T + 'static) -> Binding<U> {
+        let on_change = self.on_change;
+        Binding::new(to(self.value), move |value| on_change.emit(from(value)))
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn on_change(&self) -> &Callback<T> {
+        &self.on_change
+    }
+
+    pub fn into_parts(self) -> (T, Callback<T>) {
+        (self.value, self.on_change)
+    }
+}
+
+impl<T: Clone + 'static> From<UseStateHandle<T>> for Binding<T> {
+    fn from(handle: UseStateHandle<T>) -> Self {
+        Self::from(&handle)
+    }
+}
+
+impl<T: Clone + 'static> From<&UseStateHandle<T>> for Binding<T> {
+    fn from(handle: &UseStateHandle<T>) -> Self {
+        let value = (**handle).clone();
+        let handle = handle.clone();
+        Self::new(value, move |value| handle.set(value))
+    }
+}
+
+impl<T: 'static> From<(T, Callback<T>)> for Binding<T> {
+    fn from((value, on_change): (T, Callback<T>)) -> Self {
+        Self::new(value, on_change)
+    }
+}
+
+/// Runs `push` with the element of the tag after the current render, so the model value can be
+/// pushed into the MDC object of the element.
+///
+/// The timing contract:
+/// - `push` runs in the timeout, which is scheduled when the builder method is called. So the widget must be built in
+///   the render which mounts it, i.e. in `view` or in the function component body, rather than built beforehand and
+///   rendered later.
+/// - The timeout fires after Yew has applied the render to the DOM and has called the `rendered` methods and the
+///   effects, which usually run `mdc.autoInit`.
+/// - If the element is still not initialized by MDC at that moment, `mdc.autoInit` is run before `push`, so `push`
+///   always sees the MDC object of an auto-initialized element.
+/// - If the element is not mounted by then, `push` is skipped.
+pub(crate) fn push_after_render(tag: &VTag, push: impl FnOnce(&Element) + 'static) {
+    let node_ref = tag.node_ref.clone();
+    Timeout::new(0, move || {
+        if let Some(element) = node_ref.cast::<Element>() {
+            let is_initialized = !element.has_attribute(AUTO_INIT_ATTR)
+                || element.get_attribute(AUTO_INIT_STATE_ATTR).as_deref() == Some("initialized");
+            if !is_initialized {
+                auto_init();
+            }
+            push(&element);
+        }
+    })
+    .forget();
+}
+
+/// Set by `mdc.autoInit` on the initialized elements.
+const AUTO_INIT_STATE_ATTR: &str = "data-mdc-auto-init-state";
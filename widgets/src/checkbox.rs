@@ -1,13 +1,17 @@
 use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
 
+use yew::html::onchange;
 use yew::virtual_dom::AttrValue;
-use yew::{html, Callback, Html, MouseEvent, ToHtml};
+use yew::{html, Callback, Event, Html, MouseEvent, ToHtml};
 
+use crate::dom::JsCast;
 use crate::utils::{
     add_input_label, labeled_on_click, ripple_element, root_and_input_child_disabled, IntoWidgetWithVList,
     ManageChildren, VTagExt,
 };
-use crate::{console, MdcWidget, AUTO_INIT_ATTR};
+use crate::web_sys::HtmlInputElement;
+use crate::{console, Binding, MdcWidget, AUTO_INIT_ATTR};
 
 pub mod mdc {
     pub const TYPE_NAME: &str = "MDCCheckbox";
@@ -97,6 +101,23 @@ impl Checkbox {
         labeled_on_click(&mut self, callback);
         self
     }
+
+    /// Binds the checked state to the model.
+    pub fn bind(mut self, binding: impl Into<Binding<bool>>) -> Self {
+        let (checked, on_change) = binding.into().into_parts();
+        self = self.checked(checked);
+        if let Some(input) = self.root_tag_mut().find_child_tag_mut("input") {
+            input.add_listener(Rc::new(onchange::Wrapper::new(Callback::from(move |event: Event| {
+                if let Some(input) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                {
+                    on_change.emit(input.checked());
+                }
+            }))));
+        }
+        self
+    }
 }
 
 impl MdcWidget for Checkbox {
@@ -1,12 +1,24 @@
+use std::any::Any;
+use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
 
-use yew::html::onclick;
+use const_format::concatcp;
 use yew::virtual_dom::{AttrValue, VTag};
-use yew::{html, Callback, Html, MouseEvent, ToHtml};
+use yew::{classes, html, Callback, FocusEvent, Html, KeyboardEvent, MouseEvent, ToHtml};
 
+pub use self::export::{copy_to_clipboard, download_csv, records_to_csv, CsvOptions};
+use crate::binding::push_after_render;
+use crate::dom::{self, JsCast, JsValue};
 use crate::utils::{ManageChildren, VTagExt};
-use crate::{Checkbox, MdcWidget, AUTO_INIT_ATTR};
+use crate::wasm_bindgen::closure::Closure;
+use crate::web_sys::{Element, Event, HtmlElement, HtmlInputElement, ResizeObserver};
+use crate::{
+    js_sys, Checkbox, EventListener, HelperText, IconButton, MdcWidget, TextField, Validator, AUTO_INIT_ATTR,
+    MATERIAL_ICONS_CLASS,
+};
+
+pub mod export;
 
 pub mod mdc {
     pub const TYPE_NAME: &str = "MDCDataTable";
@@ -15,6 +27,8 @@ pub mod mdc {
 pub enum TableCell {
     Numeric(Html),
     Text(Html),
+    EditableNumeric(Html),
+    EditableText(Html),
 }
 
 impl TableCell {
@@ -26,47 +40,191 @@ impl TableCell {
         TableCell::Text(content.into())
     }
 
+    /// Numeric cell whose value can be edited in place, see [`DataTable::on_cell_edit`].
+    pub fn editable_num(value: impl Into<String>) -> Self {
+        TableCell::EditableNumeric(value.into().into())
+    }
+
+    /// Text cell whose value can be edited in place, see [`DataTable::on_cell_edit`].
+    pub fn editable_text(value: impl Into<String>) -> Self {
+        TableCell::EditableText(value.into().into())
+    }
+
     pub fn content(&self) -> &Html {
         match self {
             TableCell::Numeric(content) => content,
             TableCell::Text(content) => content,
+            TableCell::EditableNumeric(content) => content,
+            TableCell::EditableText(content) => content,
         }
     }
 
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, TableCell::Numeric(_) | TableCell::EditableNumeric(_))
+    }
+
+    pub fn is_editable(&self) -> bool {
+        matches!(self, TableCell::EditableNumeric(_) | TableCell::EditableText(_))
+    }
+
     fn build_head_cell(self) -> Html {
-        let (class, content) = match self {
-            TableCell::Numeric(content) => (
-                "mdc-data-table__header-cell mdc-data-table__header-cell--numeric",
-                content,
-            ),
-            TableCell::Text(content) => ("mdc-data-table__header-cell", content),
+        let class = if self.is_numeric() {
+            "mdc-data-table__header-cell mdc-data-table__header-cell--numeric"
+        } else {
+            "mdc-data-table__header-cell"
         };
         html! {
-            <th class = { class } role = "columnheader" scope = "col">{ content }</th>
+            <th class = { class } role = "columnheader" scope = "col">{ self.into_content() }</th>
         }
     }
 
-    fn build_body_cell(self) -> Html {
-        let (class, content) = match self {
-            TableCell::Numeric(content) => ("mdc-data-table__cell mdc-data-table__cell--numeric", content),
-            TableCell::Text(content) => ("mdc-data-table__cell", content),
-        };
-        html! {
-            <td class = { class }>{ content }</td>
+    fn build_body_cell(self, row_id: &str, column: usize) -> Html {
+        let mut class = String::from("mdc-data-table__cell");
+        if self.is_numeric() {
+            class.push_str(" mdc-data-table__cell--numeric");
+        }
+
+        if self.is_editable() {
+            class.push(' ');
+            class.push_str(DataTable::EDITABLE_CELL_CLASS);
+
+            let value = self.into_content();
+            let editor = TextField::outlined()
+                .id(format!("{}-cell-{}-editor", row_id, column))
+                .value(html_text(&value))
+                .helper_text(HelperText::new("").persistent().validation_msg());
+            html! {
+                <td class = { class } data-column = { column.to_string() } tabindex = "0">
+                    <span class = { DataTable::CELL_VALUE_CLASS }>{ value }</span>
+                    <div class = { DataTable::CELL_EDITOR_CLASS } hidden = true>{ editor }</div>
+                </td>
+            }
+        } else {
+            html! {
+                <td class = { class } data-column = { column.to_string() }>{ self.into_content() }</td>
+            }
+        }
+    }
+
+    fn into_content(self) -> Html {
+        match self {
+            TableCell::Numeric(content) => content,
+            TableCell::Text(content) => content,
+            TableCell::EditableNumeric(content) => content,
+            TableCell::EditableText(content) => content,
         }
     }
 }
 
-pub type OnRowClickFn = fn(&VTag) -> Callback<MouseEvent>;
+fn html_text(html: &Html) -> String {
+    match html {
+        Html::VText(text) => text.text.to_string(),
+        Html::VList(list) => list.iter().map(html_text).collect(),
+        Html::VTag(tag) => tag.children().map(html_text).unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+/// The value change made by the user in an editable cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellEdit {
+    pub row_id: String,
+    pub column: usize,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+/// The user interaction with a body row of the table.
+#[derive(Clone)]
+pub struct RowEvent {
+    pub row_id: String,
+    pub index: usize,
+    pub event: Event,
+    record: Option<Rc<dyn Any>>,
+}
+
+impl RowEvent {
+    /// Returns the record of the row if the row was added with a record of the type `T`,
+    /// see [`DataTable::record_row`].
+    pub fn record<T: 'static>(&self) -> Option<&T> {
+        self.record.as_deref().and_then(|record| record.downcast_ref())
+    }
+}
+
+/// How many detail rows of the table can be expanded at the same time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RowExpansion {
+    Single,
+    Multiple,
+}
 
 #[derive(Clone)]
 pub struct DataTable {
     html: Html,
     row_selection: bool,
-    on_row_click: Option<OnRowClickFn>,
+    records: Vec<Option<Rc<dyn Any>>>,
+    on_row_click: Option<Callback<RowEvent>>,
+    on_row_double_click: Option<Callback<RowEvent>>,
+    on_row_context_menu: Option<Callback<RowEvent>>,
+    on_row_activate: Option<Callback<RowEvent>>,
+    on_cell_edit: Option<Callback<CellEdit>>,
+    cell_validators: HashMap<usize, Validator>,
+    row_expansion: Option<RowExpansion>,
+    fixed_columns: usize,
+    on_row_expanded: Option<Callback<String>>,
+    on_row_collapsed: Option<Callback<String>>,
 }
 
 impl DataTable {
+    /// Marks the cell which switches to a text field on click or Enter.
+    pub const EDITABLE_CELL_CLASS: &'static str = "yew-mdc-data-table__cell--editable";
+
+    /// Indicates that the editable cell is currently being edited.
+    pub const EDITING_CELL_CLASS: &'static str = "yew-mdc-data-table__cell--editing";
+
+    /// Contains the displayed value of the editable cell.
+    pub const CELL_VALUE_CLASS: &'static str = "yew-mdc-data-table__cell-value";
+
+    /// Contains the text field of the editable cell.
+    pub const CELL_EDITOR_CLASS: &'static str = "yew-mdc-data-table__cell-editor";
+
+    /// Header cell of the column with the expand/collapse buttons.
+    pub const HEADER_CELL_EXPAND_CLASS: &'static str = "yew-mdc-data-table__header-cell--expand";
+
+    /// Cell of the row which contains the expand/collapse button.
+    pub const CELL_EXPAND_CLASS: &'static str = "yew-mdc-data-table__cell--expand";
+
+    /// The expand/collapse button of the row.
+    pub const EXPAND_BUTTON_CLASS: &'static str = "yew-mdc-data-table__expand-button";
+
+    /// Full-width row which shows the details of the preceding row.
+    pub const DETAIL_ROW_CLASS: &'static str = "yew-mdc-data-table__detail-row";
+
+    /// The single cell of the detail row.
+    pub const DETAIL_CELL_CLASS: &'static str = "yew-mdc-data-table__detail-cell";
+
+    /// Attribute of the detail row which refers to the ID of the row it belongs to.
+    pub const DETAIL_FOR_ATTR: &'static str = "data-detail-for";
+
+    /// Keeps the header row visible while the table container scrolls vertically.
+    pub const STICKY_HEADER_CLASS: &'static str = "yew-mdc-data-table--sticky-header";
+
+    /// Marks the header cell of a fixed leading column.
+    pub const HEADER_CELL_FIXED_CLASS: &'static str = "yew-mdc-data-table__header-cell--fixed";
+
+    /// Marks the body cell of a fixed leading column.
+    pub const CELL_FIXED_CLASS: &'static str = "yew-mdc-data-table__cell--fixed";
+
+    /// Marks the header and body cells of the last fixed column, which casts the scroll shadow.
+    pub const CELL_FIXED_LAST_CLASS: &'static str = "yew-mdc-data-table__cell--fixed-last";
+
+    const FIXED_OFFSET_VAR: &'static str = "--yew-mdc-data-table-fixed-offset";
+    const FIXED_COLUMN_SHADOW_VAR: &'static str = "--yew-mdc-data-table-fixed-column-shadow";
+    const STICKY_HEADER_SHADOW_VAR: &'static str = "--yew-mdc-data-table-sticky-header-shadow";
+    const FIXED_COLUMN_SHADOW: &'static str = "2px 0 4px -2px rgba(0, 0, 0, 0.24)";
+    const STICKY_HEADER_SHADOW: &'static str = "0 2px 4px -2px rgba(0, 0, 0, 0.24)";
+    const NO_SHADOW: &'static str = "0 0 transparent";
+
     pub fn new(id: impl Into<String>) -> Self {
         let id = id.into();
         let mut table = Self {
@@ -84,7 +242,17 @@ impl DataTable {
                 </div>
             },
             row_selection: false,
+            records: Vec::new(),
             on_row_click: None,
+            on_row_double_click: None,
+            on_row_context_menu: None,
+            on_row_activate: None,
+            on_cell_edit: None,
+            cell_validators: HashMap::new(),
+            row_expansion: None,
+            fixed_columns: 0,
+            on_row_expanded: None,
+            on_row_collapsed: None,
         };
         table.root_tag_mut().set_attr(AUTO_INIT_ATTR, mdc::TYPE_NAME);
         table
@@ -94,10 +262,15 @@ impl DataTable {
         let head_cells: Vec<Html> = head.into_iter().map(|cell| cell.build_head_cell()).collect();
 
         let header_row = self.table_header_row_tag_mut();
-        for idx in 0..header_row.children_count() {
+        for idx in (0..header_row.children_count()).rev() {
             if header_row
                 .get_child(idx)
-                .map(|child| !child.is_contains_class("mdc-data-table__header-cell--checkbox"))
+                .map(|child| {
+                    !child.is_contains_any_class(&[
+                        "mdc-data-table__header-cell--checkbox",
+                        Self::HEADER_CELL_EXPAND_CLASS,
+                    ])
+                })
                 .unwrap_or(false)
             {
                 header_row.remove_child(idx);
@@ -111,12 +284,53 @@ impl DataTable {
         self
     }
 
-    pub fn row(mut self, row: impl IntoIterator<Item = TableCell>) -> Self {
-        let row: Vec<_> = row.into_iter().map(|cell| cell.build_body_cell()).collect();
+    pub fn row(self, row: impl IntoIterator<Item = TableCell>) -> Self {
+        self.add_row(row, None, None)
+    }
+
+    /// Adds the row with the detail content which is shown in a full-width row below it when the
+    /// row is expanded. Requires [`DataTable::expandable_rows`] to be enabled.
+    pub fn row_with_detail(self, row: impl IntoIterator<Item = TableCell>, detail: impl Into<Html>) -> Self {
+        self.add_row(row, Some(detail.into()), None)
+    }
+
+    /// Adds the row which is built from the record. The record is passed to the row event
+    /// callbacks, see [`RowEvent::record`].
+    pub fn record_row<T: 'static>(self, record: T, row: impl IntoIterator<Item = TableCell>) -> Self {
+        self.add_row(row, None, Some(Rc::new(record)))
+    }
+
+    /// Adds a row for each record, the cells of the row are built by `to_row`.
+    pub fn records<T: 'static, R: IntoIterator<Item = TableCell>>(
+        mut self,
+        records: impl IntoIterator<Item = T>,
+        to_row: impl Fn(&T) -> R,
+    ) -> Self {
+        for record in records {
+            let row = to_row(&record);
+            self = self.record_row(record, row);
+        }
+        self
+    }
+
+    fn add_row(
+        mut self,
+        row: impl IntoIterator<Item = TableCell>,
+        detail: Option<Html>,
+        record: Option<Rc<dyn Any>>,
+    ) -> Self {
+        let row_index = self.row_count();
+        let row_id = format!("{}-row-{}", self.root_id(), row_index);
+        let row: Vec<_> = row
+            .into_iter()
+            .enumerate()
+            .map(|(column, cell)| cell.build_body_cell(&row_id, column))
+            .collect();
 
-        let row_id = format!("{}-row-{}", self.root_id(), self.row_count());
         let mut row = html! {
-            <tr data-row-id = { row_id.clone() } class = "mdc-data-table__row">{ row }</tr>
+            <tr data-row-id = { row_id.clone() } data-row-index = { row_index.to_string() } class = "mdc-data-table__row">
+                { row }
+            </tr>
         };
 
         let row_checkbox = if self.row_selection {
@@ -127,7 +341,7 @@ impl DataTable {
 
         if let Some(cell) = row.find_child_tag_mut("td") {
             cell.set_attr("scope", "row");
-            cell.set_attr("id", row_id);
+            cell.set_attr("id", row_id.clone());
         }
 
         if let Some(row_checkbox) = row_checkbox {
@@ -136,23 +350,115 @@ impl DataTable {
             }
         }
 
-        if let Some(on_row_click) = self.on_row_click {
-            Self::add_on_click_to_row(&mut row, on_row_click);
+        if self.row_expansion.is_some() {
+            let expand_cell = Self::row_expand_cell(&row_id, detail.is_some());
+            if let Html::VTag(row) = &mut row {
+                Self::insert_expand_cell(row, expand_cell);
+            }
         }
 
-        self.table_body_tag_mut().add_child(row);
+        self.records.push(record);
+
+        let body = self.table_body_tag_mut();
+        body.add_child(row);
+        if let Some(detail) = detail {
+            body.add_child(html! {
+                <tr class = { Self::DETAIL_ROW_CLASS } data-detail-for = { row_id } hidden = true>
+                    <td class = { classes!("mdc-data-table__cell", Self::DETAIL_CELL_CLASS) } colspan = "1">
+                        { detail }
+                    </td>
+                </tr>
+            });
+        }
         self
     }
 
-    pub fn on_row_click(mut self, on_row_click: OnRowClickFn) -> Self {
-        self.on_row_click = Some(on_row_click);
+    /// Adds the column of expand/collapse buttons, which show and hide the detail rows added by
+    /// [`DataTable::row_with_detail`].
+    pub fn expandable_rows(mut self, expansion: RowExpansion) -> Self {
+        if self.row_expansion.is_none() {
+            let header_row = self.table_header_row_tag_mut();
+            Self::insert_expand_cell(header_row, html! {
+                <th class = { classes!("mdc-data-table__header-cell", Self::HEADER_CELL_EXPAND_CLASS) }
+                    role = "columnheader" scope = "col"></th>
+            });
 
-        let body = self.table_body_tag_mut();
-        if let Some(children) = body.children_mut().map(|children| children.to_vlist_mut()) {
-            for row in children.iter_mut() {
-                Self::add_on_click_to_row(row, on_row_click);
+            let body = self.table_body_tag_mut();
+            let mut detail_ids = Vec::new();
+            for idx in 0..body.children_count() {
+                if let Some(id) = body.get_child(idx).and_then(|row| row.attr(Self::DETAIL_FOR_ATTR)) {
+                    detail_ids.push(id);
+                }
+            }
+            if let Some(children) = body.children_mut().map(|children| children.to_vlist_mut()) {
+                for row in children.iter_mut() {
+                    if let Html::VTag(row) = row {
+                        if let Some(row_id) = row.attr("data-row-id") {
+                            let has_detail = detail_ids.contains(&row_id);
+                            Self::insert_expand_cell(row, Self::row_expand_cell(&row_id, has_detail));
+                        }
+                    }
+                }
             }
         }
+        self.row_expansion = Some(expansion);
+        self
+    }
+
+    /// Emits the row ID when the detail row of the row is expanded.
+    pub fn on_row_expanded(mut self, callback: impl Into<Callback<String>>) -> Self {
+        self.on_row_expanded = Some(callback.into());
+        self
+    }
+
+    /// Emits the row ID when the detail row of the row is collapsed.
+    pub fn on_row_collapsed(mut self, callback: impl Into<Callback<String>>) -> Self {
+        self.on_row_collapsed = Some(callback.into());
+        self
+    }
+
+    /// Emits when a body row is clicked.
+    pub fn on_row_click(mut self, callback: impl Into<Callback<RowEvent>>) -> Self {
+        self.on_row_click = Some(callback.into());
+        self
+    }
+
+    /// Emits when a body row is double-clicked.
+    pub fn on_row_double_click(mut self, callback: impl Into<Callback<RowEvent>>) -> Self {
+        self.on_row_double_click = Some(callback.into());
+        self
+    }
+
+    /// Emits when the context menu is requested on a body row. Call `event.prevent_default()` on
+    /// [`RowEvent::event`] to suppress the browser context menu.
+    pub fn on_row_context_menu(mut self, callback: impl Into<Callback<RowEvent>>) -> Self {
+        self.on_row_context_menu = Some(callback.into());
+        self
+    }
+
+    /// Emits when Enter is pressed on a focused body row. The rows become focusable.
+    pub fn on_row_activate(mut self, callback: impl Into<Callback<RowEvent>>) -> Self {
+        self.on_row_activate = Some(callback.into());
+        self
+    }
+
+    /// Emits when the user commits a new value of an editable cell. The editing starts on click or
+    /// Enter, commits on Enter or blur and cancels on Escape.
+    pub fn on_cell_edit(mut self, callback: impl Into<Callback<CellEdit>>) -> Self {
+        self.on_cell_edit = Some(callback.into());
+        self
+    }
+
+    /// Sets the validator of the editable cells of the column. An invalid value is not committed,
+    /// the error is shown in the helper text of the cell editor instead.
+    pub fn column_validator(self, column: usize, validator: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        self.column_validator_with(column, Validator::new(validator))
+    }
+
+    /// Sets the validator of the editable cells of the column, see
+    /// [`column_validator`](Self::column_validator).
+    pub fn column_validator_with(mut self, column: usize, validator: Validator) -> Self {
+        self.cell_validators.insert(column, validator);
         self
     }
 
@@ -172,8 +478,9 @@ impl DataTable {
             if let Some(children) = body.children_mut().map(|children| children.to_vlist_mut()) {
                 for row in children.iter_mut() {
                     if let Html::VTag(row) = row {
-                        let row_id = row.attr("id").expect("A row ID expected").clone();
-                        row.insert_child(0, Self::row_checkbox(row_id));
+                        if let Some(row_id) = row.attr("data-row-id") {
+                            row.insert_child(0, Self::row_checkbox(row_id));
+                        }
                     }
                 }
             }
@@ -199,8 +506,35 @@ impl DataTable {
         self
     }
 
+    /// Keeps the header row visible while the rows scroll. The table container should have a
+    /// limited height, e.g. `.mdc-data-table__table-container { max-height: 400px; }`.
+    pub fn sticky_header(self) -> Self {
+        self.class(Self::STICKY_HEADER_CLASS)
+    }
+
+    /// Keeps the first `count` columns visible while the table scrolls horizontally. The
+    /// selection checkbox and the expand button columns are fixed as well.
+    pub fn fixed_columns(mut self, count: usize) -> Self {
+        self.fixed_columns = count;
+        self
+    }
+
+    /// Recalculates the offsets of the fixed columns and the scroll shadows of the existing table.
+    /// It is called automatically when the table container scrolls.
+    pub fn layout_existing(id: impl AsRef<str>) {
+        let root = dom::existing::get_element_by_id::<HtmlElement>(id.as_ref());
+        layout_sticky_cells(&root);
+    }
+
     pub fn row_count(&self) -> usize {
-        self.table_body_tag().children_count()
+        let body = self.table_body_tag();
+        (0..body.children_count())
+            .filter(|&idx| {
+                body.get_child(idx)
+                    .map(|row| !row.is_contains_class(Self::DETAIL_ROW_CLASS))
+                    .unwrap_or(false)
+            })
+            .count()
     }
 
     pub fn root_id(&self) -> AttrValue {
@@ -256,12 +590,299 @@ impl DataTable {
         }
     }
 
-    fn add_on_click_to_row(row: &mut Html, on_row_click: OnRowClickFn) -> bool {
-        if let Html::VTag(tag) = row {
-            let callback = on_row_click(tag);
-            tag.add_listener(Rc::new(onclick::Wrapper::new(callback)))
+    fn add_row_event_listeners(mut self) -> Self {
+        let records = Rc::new(self.records.clone());
+        let row_event_callback = |callback: Callback<RowEvent>, only_focused_row: bool| {
+            let records = records.clone();
+            Callback::from(move |event: Event| {
+                if let Some(row_event) = row_event(event, &records, only_focused_row) {
+                    callback.emit(row_event);
+                }
+            })
+        };
+
+        if let Some(callback) = self.on_row_click.clone() {
+            self = self.on_event("click", row_event_callback(callback, false));
+        }
+        if let Some(callback) = self.on_row_double_click.clone() {
+            self = self.on_event("dblclick", row_event_callback(callback, false));
+        }
+        if let Some(callback) = self.on_row_context_menu.clone() {
+            self = self.on_event("contextmenu", row_event_callback(callback, false));
+        }
+        if let Some(callback) = self.on_row_activate.clone() {
+            let on_enter = row_event_callback(callback, true);
+            self = self.on_event(
+                "keydown",
+                Callback::from(move |event: KeyboardEvent| {
+                    if event.key() == "Enter" {
+                        on_enter.emit(event.into());
+                    }
+                }),
+            );
+
+            let body = self.table_body_tag_mut();
+            if let Some(children) = body.children_mut().map(|children| children.to_vlist_mut()) {
+                for row in children.iter_mut() {
+                    if row.is_contains_class("mdc-data-table__row") {
+                        row.set_attr("tabindex", "0");
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    fn add_cell_editing_listeners(self) -> Self {
+        let on_cell_edit = self.on_cell_edit.clone();
+        let validators = Rc::new(self.cell_validators.clone());
+
+        let on_keydown = {
+            let on_cell_edit = on_cell_edit.clone();
+            let validators = validators.clone();
+            move |event: KeyboardEvent| {
+                let Some(target) = event.target().and_then(|target| target.dyn_into::<Element>().ok()) else {
+                    return;
+                };
+                if target.tag_name().eq_ignore_ascii_case("input") {
+                    if let Some(cell) = editing_cell(&target) {
+                        match event.key().as_str() {
+                            "Enter" => {
+                                event.prevent_default();
+                                commit_cell_editing(&cell, &validators, on_cell_edit.as_ref());
+                            },
+                            "Escape" => {
+                                event.prevent_default();
+                                cancel_cell_editing(&cell);
+                            },
+                            _ => (),
+                        }
+                    }
+                } else if event.key() == "Enter" && target.class_list().contains(Self::EDITABLE_CELL_CLASS) {
+                    event.prevent_default();
+                    start_cell_editing(&target);
+                }
+            }
+        };
+
+        let on_focusout = move |event: FocusEvent| {
+            let Some(target) = event.target().and_then(|target| target.dyn_into::<Element>().ok()) else {
+                return;
+            };
+            if target.tag_name().eq_ignore_ascii_case("input") {
+                if let Some(cell) = editing_cell(&target) {
+                    commit_cell_editing(&cell, &validators, on_cell_edit.as_ref());
+                }
+            }
+        };
+
+        let on_click = |event: MouseEvent| {
+            if let Some(cell) = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|target| {
+                    target
+                        .closest(&format!(".{}", Self::EDITABLE_CELL_CLASS))
+                        .ok()
+                        .flatten()
+                })
+            {
+                start_cell_editing(&cell);
+            }
+        };
+
+        self.on_event("click", Callback::from(on_click))
+            .on_event("keydown", Callback::from(on_keydown))
+            .on_event("focusout", Callback::from(on_focusout))
+    }
+
+    fn add_row_expansion_listener(self, expansion: RowExpansion) -> Self {
+        let on_row_expanded = self.on_row_expanded.clone();
+        let on_row_collapsed = self.on_row_collapsed.clone();
+
+        self.on_event(
+            "click",
+            Callback::from(move |event: MouseEvent| {
+                let Some(row) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .and_then(|target| {
+                        target
+                            .closest(&format!(".{}", Self::EXPAND_BUTTON_CLASS))
+                            .ok()
+                            .flatten()
+                    })
+                    .and_then(|button| button.closest("tr").ok().flatten())
+                else {
+                    return;
+                };
+                let (Some(row_id), Some(body)) = (row.get_attribute("data-row-id"), row.parent_element()) else {
+                    return;
+                };
+
+                let expand = !is_row_expanded(&body, &row_id);
+                if expand && expansion == RowExpansion::Single {
+                    for other_id in expanded_row_ids(&body) {
+                        set_row_expanded(&body, &other_id, false);
+                        if let Some(on_row_collapsed) = &on_row_collapsed {
+                            on_row_collapsed.emit(other_id);
+                        }
+                    }
+                }
+
+                set_row_expanded(&body, &row_id, expand);
+                if let Some(callback) = if expand { &on_row_expanded } else { &on_row_collapsed } {
+                    callback.emit(row_id);
+                }
+            }),
+        )
+    }
+
+    fn set_sticky_cells_style(&mut self) {
+        let sticky_header = self.root_tag().is_contains_class(Self::STICKY_HEADER_CLASS);
+        let fixed_columns = self.fixed_columns;
+        if !sticky_header && fixed_columns == 0 {
+            return;
+        }
+
+        let header_row = self.table_header_row_tag_mut();
+        Self::set_row_sticky_cells_style(header_row, fixed_columns, true);
+
+        let body = self.table_body_tag_mut();
+        if let Some(children) = body.children_mut().map(|children| children.to_vlist_mut()) {
+            for row in children.iter_mut() {
+                if let Html::VTag(row) = row {
+                    if row.is_contains_class("mdc-data-table__row") {
+                        Self::set_row_sticky_cells_style(row, fixed_columns, false);
+                    }
+                }
+            }
+        }
+
+        if let Some(container) = self.root_tag_mut().first_child_tag_mut() {
+            container.add_listener(Rc::new(EventListener::new(
+                "scroll",
+                Callback::from(|event: Event| {
+                    if let Some(root) = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<Element>().ok())
+                        .and_then(|container| container.parent_element())
+                        .and_then(|root| root.dyn_into::<HtmlElement>().ok())
+                    {
+                        layout_sticky_cells(&root);
+                    }
+                }),
+            )));
+        }
+
+        // The offsets of the fixed columns depend on the rendered widths of the cells
+        push_after_render(self.root_tag(), |root| {
+            if let Some(root) = root.dyn_ref::<HtmlElement>() {
+                layout_sticky_cells(root);
+                observe_fixed_cells_resize(root);
+            }
+        });
+    }
+
+    fn set_row_sticky_cells_style(row: &mut VTag, fixed_columns: usize, is_header: bool) {
+        let control_classes = [
+            "mdc-data-table__header-cell--checkbox",
+            "mdc-data-table__cell--checkbox",
+            Self::HEADER_CELL_EXPAND_CLASS,
+            Self::CELL_EXPAND_CLASS,
+        ];
+        let cells_count = row.children_count();
+        let mut fixed_cells_count = 0;
+        let mut data_cells_count = 0;
+        for idx in 0..cells_count {
+            match row.get_child(idx) {
+                Some(cell) if cell.is_contains_any_class(&control_classes) => fixed_cells_count += 1,
+                Some(_) if data_cells_count < fixed_columns => {
+                    fixed_cells_count += 1;
+                    data_cells_count += 1;
+                },
+                _ => break,
+            }
+        }
+        if fixed_columns == 0 {
+            fixed_cells_count = 0;
+        }
+
+        // The cells are styled by `yew-mdc-widgets.css`, the offsets of the fixed columns are
+        // computed by `layout_sticky_cells` on the table root
+        for idx in 0..fixed_cells_count {
+            let Some(cell) = row.get_child_mut(idx) else {
+                continue;
+            };
+            cell.add_class_if_needed(if is_header {
+                Self::HEADER_CELL_FIXED_CLASS
+            } else {
+                Self::CELL_FIXED_CLASS
+            });
+            if idx + 1 == fixed_cells_count {
+                cell.add_class_if_needed(Self::CELL_FIXED_LAST_CLASS);
+            }
+
+            let offset = format!(
+                "{}: var({}-{}, 0px);",
+                Self::FIXED_OFFSET_VAR,
+                Self::FIXED_OFFSET_VAR,
+                idx
+            );
+            let style = match cell.attr("style") {
+                Some(own) if !own.trim().is_empty() => format!("{} {}", offset, own.trim()),
+                _ => offset,
+            };
+            cell.set_attr("style", style);
+        }
+    }
+
+    fn set_detail_colspan(&mut self) {
+        let colspan = self.table_header_row_tag_mut().children_count().max(1).to_string();
+        let body = self.table_body_tag_mut();
+        if let Some(children) = body.children_mut().map(|children| children.to_vlist_mut()) {
+            for row in children.iter_mut() {
+                if row.is_contains_class(Self::DETAIL_ROW_CLASS) {
+                    if let Some(cell) = row.find_child_tag_mut("td") {
+                        cell.set_attr("colspan", colspan.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    fn has_editable_cells(&self) -> bool {
+        let body = self.table_body_tag();
+        (0..body.children_count()).any(|idx| {
+            body.get_child(idx)
+                .map(|row| row.is_some_child_contains_class(Self::EDITABLE_CELL_CLASS))
+                .unwrap_or(false)
+        })
+    }
+
+    fn insert_expand_cell(row: &mut VTag, cell: Html) {
+        let idx = if row.is_first_child_contains_class("mdc-data-table__header-cell--checkbox")
+            || row.is_first_child_contains_class("mdc-data-table__cell--checkbox")
+        {
+            1
         } else {
-            false
+            0
+        };
+        row.insert_child(idx, cell);
+    }
+
+    fn row_expand_cell(row_id: &str, has_detail: bool) -> Html {
+        let button = has_detail.then(|| {
+            IconButton::new()
+                .id(format!("{}-expand-button", row_id))
+                .class(Self::EXPAND_BUTTON_CLASS)
+                .attr("aria-expanded", "false")
+                .attr("aria-label", "Toggle row details")
+                .item(html! { <i class = { classes!(MATERIAL_ICONS_CLASS, IconButton::ICON_ON_CLASS) }>{ "expand_less" }</i> })
+                .item(html! { <i class = { MATERIAL_ICONS_CLASS }>{ "expand_more" }</i> })
+        });
+        html! {
+            <td class = { classes!("mdc-data-table__cell", Self::CELL_EXPAND_CLASS) }>{ for button }</td>
         }
     }
 
@@ -291,6 +912,255 @@ impl DataTable {
     }
 }
 
+fn layout_sticky_cells(root: &HtmlElement) {
+    let style = root.style();
+    if let Ok(Some(header_row)) = root.query_selector(".mdc-data-table__header-row") {
+        if let Ok(cells) = header_row.query_selector_all(&format!(":scope > .{}", DataTable::HEADER_CELL_FIXED_CLASS)) {
+            let mut offset = 0;
+            for idx in 0..cells.length() {
+                if let Some(cell) = cells.item(idx).and_then(|cell| cell.dyn_into::<HtmlElement>().ok()) {
+                    let _ = style.set_property(
+                        &format!("{}-{}", DataTable::FIXED_OFFSET_VAR, idx),
+                        &format!("{}px", offset),
+                    );
+                    offset += cell.offset_width();
+                }
+            }
+        }
+    }
+
+    if let Some(container) = root.first_element_child() {
+        let shadow = |scrolled: bool, shadow: &str| {
+            if scrolled {
+                shadow.to_string()
+            } else {
+                DataTable::NO_SHADOW.to_string()
+            }
+        };
+        let _ = style.set_property(
+            DataTable::FIXED_COLUMN_SHADOW_VAR,
+            &shadow(container.scroll_left() > 0, DataTable::FIXED_COLUMN_SHADOW),
+        );
+        let _ = style.set_property(
+            DataTable::STICKY_HEADER_SHADOW_VAR,
+            &shadow(container.scroll_top() > 0, DataTable::STICKY_HEADER_SHADOW),
+        );
+    }
+}
+
+/// Lays out the sticky cells again, when the fixed header cells are resized, e.g. when the window
+/// is resized or the column content is changed.
+fn observe_fixed_cells_resize(root: &HtmlElement) {
+    let observer = match js_sys::Reflect::get(root, &JsValue::from_str(RESIZE_OBSERVER_PROPERTY)) {
+        Ok(observer) if !observer.is_undefined() => observer.unchecked_into::<ResizeObserver>(),
+        _ => {
+            let layout_root = root.clone();
+            let callback = Closure::<dyn Fn()>::new(move || layout_sticky_cells(&layout_root)).into_js_value();
+            let Ok(observer) = ResizeObserver::new(callback.unchecked_ref()) else {
+                return;
+            };
+            let _ = js_sys::Reflect::set(root, &JsValue::from_str(RESIZE_OBSERVER_PROPERTY), &observer);
+            observer
+        },
+    };
+
+    // Observing of the already observed cell is no-op, the removed cells are unobserved by GC
+    if let Ok(cells) = root.query_selector_all(concatcp!(".", DataTable::HEADER_CELL_FIXED_CLASS)) {
+        for idx in 0..cells.length() {
+            if let Some(cell) = cells.item(idx).and_then(|cell| cell.dyn_into::<Element>().ok()) {
+                observer.observe(&cell);
+            }
+        }
+    }
+}
+
+const RESIZE_OBSERVER_PROPERTY: &str = "mdcDataTableResizeObserver";
+
+const ROW_CONTROLS_SELECTOR: &str = concatcp!(
+    ".mdc-data-table__cell--checkbox, .",
+    DataTable::CELL_EXPAND_CLASS,
+    ", .",
+    DataTable::CELL_EDITOR_CLASS,
+    ", .",
+    DataTable::EDITING_CELL_CLASS,
+);
+
+fn row_event(event: Event, records: &[Option<Rc<dyn Any>>], only_focused_row: bool) -> Option<RowEvent> {
+    let target = event.target()?.dyn_into::<Element>().ok()?;
+    let row = target.closest("tr.mdc-data-table__row").ok()??;
+    if only_focused_row && row != target {
+        return None;
+    }
+    // The row checkbox, the expand button and the cell editors handle their events by themselves
+    if target.closest(ROW_CONTROLS_SELECTOR).ok().flatten().is_some() {
+        return None;
+    }
+
+    let row_id = row.get_attribute("data-row-id")?;
+    let index = row.get_attribute("data-row-index")?.parse().ok()?;
+    let record = records.get(index).cloned().flatten();
+    Some(RowEvent {
+        row_id,
+        index,
+        event,
+        record,
+    })
+}
+
+fn detail_row(body: &Element, row_id: &str) -> Option<HtmlElement> {
+    body.query_selector(&format!("[{}='{}']", DataTable::DETAIL_FOR_ATTR, row_id))
+        .ok()
+        .flatten()
+        .and_then(|row| row.dyn_into::<HtmlElement>().ok())
+}
+
+fn is_row_expanded(body: &Element, row_id: &str) -> bool {
+    detail_row(body, row_id).map(|row| !row.hidden()).unwrap_or(false)
+}
+
+fn expanded_row_ids(body: &Element) -> Vec<String> {
+    let mut ids = Vec::new();
+    if let Ok(rows) = body.query_selector_all(&format!(".{}:not([hidden])", DataTable::DETAIL_ROW_CLASS)) {
+        for idx in 0..rows.length() {
+            if let Some(id) = rows
+                .item(idx)
+                .and_then(|row| row.dyn_into::<Element>().ok())
+                .and_then(|row| row.get_attribute(DataTable::DETAIL_FOR_ATTR))
+            {
+                ids.push(id);
+            }
+        }
+    }
+    ids
+}
+
+fn set_row_expanded(body: &Element, row_id: &str, expanded: bool) {
+    if let Some(detail) = detail_row(body, row_id) {
+        detail.set_hidden(!expanded);
+    }
+    if let Ok(Some(button)) = body.query_selector(&format!(
+        "[data-row-id='{}'] .{}",
+        row_id,
+        DataTable::EXPAND_BUTTON_CLASS
+    )) {
+        let _ = if expanded {
+            button.class_list().add_1(IconButton::ON_CLASS)
+        } else {
+            button.class_list().remove_1(IconButton::ON_CLASS)
+        };
+        let _ = button.set_attribute("aria-expanded", if expanded { "true" } else { "false" });
+    }
+}
+
+fn editing_cell(input: &Element) -> Option<Element> {
+    input
+        .closest(&format!(".{}", DataTable::EDITING_CELL_CLASS))
+        .ok()
+        .flatten()
+}
+
+fn cell_parts(cell: &Element) -> Option<(HtmlElement, HtmlElement, HtmlInputElement)> {
+    let select = |class: &str| {
+        cell.query_selector(&format!(".{}", class))
+            .ok()
+            .flatten()
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    };
+    let value = select(DataTable::CELL_VALUE_CLASS)?;
+    let editor = select(DataTable::CELL_EDITOR_CLASS)?;
+    let input = editor
+        .query_selector("input")
+        .ok()
+        .flatten()
+        .and_then(|input| input.dyn_into::<HtmlInputElement>().ok())?;
+    Some((value, editor, input))
+}
+
+fn set_cell_error(cell: &Element, error: Option<&str>) {
+    if let Ok(Some(text_field)) = cell.query_selector(&format!(".{}", TextField::CLASS)) {
+        let _ = if error.is_some() {
+            text_field.class_list().add_1(TextField::INVALID_CLASS)
+        } else {
+            text_field.class_list().remove_1(TextField::INVALID_CLASS)
+        };
+    }
+    if let Ok(Some(helper_text)) = cell.query_selector(&format!(".{}", HelperText::CLASS)) {
+        helper_text.set_text_content(error);
+    }
+}
+
+fn start_cell_editing(cell: &Element) {
+    if cell.class_list().contains(DataTable::EDITING_CELL_CLASS) {
+        return;
+    }
+    if let Some((value, editor, input)) = cell_parts(cell) {
+        let _ = cell.class_list().add_1(DataTable::EDITING_CELL_CLASS);
+        input.set_value(&value.text_content().unwrap_or_default());
+        set_cell_error(cell, None);
+        value.set_hidden(true);
+        editor.set_hidden(false);
+        let _ = input.focus();
+        input.select();
+    }
+}
+
+fn finish_cell_editing(cell: &Element, value: &HtmlElement, editor: &HtmlElement) {
+    let _ = cell.class_list().remove_1(DataTable::EDITING_CELL_CLASS);
+    editor.set_hidden(true);
+    value.set_hidden(false);
+    if let Some(cell) = cell.dyn_ref::<HtmlElement>() {
+        let _ = cell.focus();
+    }
+}
+
+fn commit_cell_editing(
+    cell: &Element,
+    validators: &HashMap<usize, Validator>,
+    on_cell_edit: Option<&Callback<CellEdit>>,
+) {
+    let Some((value, editor, input)) = cell_parts(cell) else {
+        return;
+    };
+    let column = cell
+        .get_attribute("data-column")
+        .and_then(|column| column.parse().ok())
+        .unwrap_or_default();
+    let old_value = value.text_content().unwrap_or_default();
+    let new_value = input.value();
+
+    if let Some(Err(error)) = validators.get(&column).map(|validator| validator.validate(&new_value)) {
+        set_cell_error(cell, Some(&error));
+        return;
+    }
+
+    finish_cell_editing(cell, &value, &editor);
+    if old_value != new_value {
+        value.set_text_content(Some(&new_value));
+        if let Some(on_cell_edit) = on_cell_edit {
+            let row_id = cell
+                .closest("tr")
+                .ok()
+                .flatten()
+                .and_then(|row| row.get_attribute("data-row-id"))
+                .unwrap_or_default();
+            on_cell_edit.emit(CellEdit {
+                row_id,
+                column,
+                old_value,
+                new_value,
+            });
+        }
+    }
+}
+
+fn cancel_cell_editing(cell: &Element) {
+    if let Some((value, editor, input)) = cell_parts(cell) {
+        input.set_value(&value.text_content().unwrap_or_default());
+        set_cell_error(cell, None);
+        finish_cell_editing(cell, &value, &editor);
+    }
+}
+
 impl MdcWidget for DataTable {
     const NAME: &'static str = stringify!(DataTable);
 
@@ -318,7 +1188,16 @@ impl DerefMut for DataTable {
 }
 
 impl From<DataTable> for Html {
-    fn from(widget: DataTable) -> Self {
+    fn from(mut widget: DataTable) -> Self {
+        if widget.has_editable_cells() {
+            widget = widget.add_cell_editing_listeners();
+        }
+        if let Some(expansion) = widget.row_expansion {
+            widget.set_detail_colspan();
+            widget = widget.add_row_expansion_listener(expansion);
+        }
+        widget.set_sticky_cells_style();
+        widget = widget.add_row_event_listeners();
         widget.html
     }
 }
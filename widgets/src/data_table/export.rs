@@ -0,0 +1,314 @@
+use std::borrow::Cow;
+
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::prelude::*;
+use yew::virtual_dom::VTag;
+use yew::Html;
+
+use super::{html_text, DataTable};
+use crate::dom::{self, JsCast};
+use crate::utils::{ManageChildren, VTagExt};
+use crate::web_sys::{Blob, BlobPropertyBag, Element, HtmlElement, Url};
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(catch, js_namespace = ["navigator", "clipboard"], js_name = writeText)]
+    fn clipboard_write_text(text: &str) -> Result<js_sys::Promise, JsValue>;
+}
+
+pub const CSV_MIME_TYPE: &str = "text/csv;charset=utf-8";
+
+const CONTROL_CELL_CLASSES: [&str; 4] = [
+    "mdc-data-table__header-cell--checkbox",
+    "mdc-data-table__cell--checkbox",
+    DataTable::HEADER_CELL_EXPAND_CLASS,
+    DataTable::CELL_EXPAND_CLASS,
+];
+
+/// The delay of revoking the object URL of the downloaded file.
+const REVOKE_URL_DELAY_MS: u32 = 10_000;
+
+const ROW_SELECTED_CLASS: &str = "mdc-data-table__row--selected";
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Skips the columns which are hidden.
+    pub visible_columns_only: bool,
+
+    /// Exports only the selected rows instead of all rows of the table body.
+    pub selected_rows_only: bool,
+}
+
+impl CsvOptions {
+    pub fn visible_columns_only(mut self, visible_columns_only: bool) -> Self {
+        self.visible_columns_only = visible_columns_only;
+        self
+    }
+
+    pub fn selected_rows_only(mut self, selected_rows_only: bool) -> Self {
+        self.selected_rows_only = selected_rows_only;
+        self
+    }
+}
+
+/// Escapes the field according to RFC 4180: a field containing a comma, a double quote or a line
+/// break is enclosed in double quotes, and the double quotes inside it are doubled.
+pub fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Builds CSV text from the records, e.g. from a typed row source of the table. The records are
+/// separated by CRLF.
+pub fn records_to_csv<R, F>(records: impl IntoIterator<Item = R>) -> String
+where
+    R: IntoIterator<Item = F>,
+    F: AsRef<str>,
+{
+    let mut csv = String::new();
+    for record in records {
+        for (idx, field) in record.into_iter().enumerate() {
+            if idx > 0 {
+                csv.push(',');
+            }
+            csv.push_str(&csv_field(field.as_ref()));
+        }
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Triggers the browser download of the CSV text as a file with the given name.
+pub fn download_csv(file_name: impl AsRef<str>, csv: impl AsRef<str>) -> Result<(), JsValue> {
+    let parts = js_sys::Array::of1(&JsValue::from_str(csv.as_ref()));
+    let properties = BlobPropertyBag::new();
+    properties.set_type(CSV_MIME_TYPE);
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &properties)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = dom::existing::document();
+    let link = document.create_element("a")?.unchecked_into::<HtmlElement>();
+    link.set_attribute("href", &url)?;
+    link.set_attribute("download", file_name.as_ref())?;
+    link.set_hidden(true);
+    if let Some(body) = document.body() {
+        body.append_child(&link)?;
+        link.click();
+        body.remove_child(&link)?;
+    } else {
+        link.click();
+    }
+    // Some browsers start the download asynchronously, so the URL is revoked later
+    Timeout::new(REVOKE_URL_DELAY_MS, move || {
+        let _ = Url::revoke_object_url(&url);
+    })
+    .forget();
+    Ok(())
+}
+
+/// Writes the text to the clipboard. The returned promise resolves when the text is written. The
+/// clipboard is not available on the insecure origins, then the error is returned.
+pub fn copy_to_clipboard(text: impl AsRef<str>) -> Result<js_sys::Promise, JsValue> {
+    clipboard_write_text(text.as_ref())
+}
+
+impl DataTable {
+    /// Exports the head and the body rows of the widget as CSV text. The selection checkbox and
+    /// the expand button columns, as well as the detail rows, are not exported.
+    pub fn to_csv(&self, options: CsvOptions) -> String {
+        let table = self.table_tag();
+        let head_cells = table
+            .find_child_tag("thead")
+            .and_then(|head| head.find_child_tag("tr"))
+            .map(data_cells)
+            .unwrap_or_default();
+        let visible_columns: Vec<bool> = head_cells.iter().map(|cell| is_cell_visible(cell)).collect();
+        let is_column_exported = |idx: usize, cell: &VTag| {
+            !options.visible_columns_only
+                || (visible_columns.get(idx).copied().unwrap_or(true) && is_cell_visible(cell))
+        };
+
+        let body = self.table_body_tag();
+        let rows = (0..body.children_count())
+            .filter_map(|idx| body.get_child(idx).and_then(|row| row.root_tag()))
+            .filter(|row| row.is_contains_class("mdc-data-table__row"))
+            .filter(|row| !options.selected_rows_only || is_row_tag_selected(row));
+
+        let head = (!head_cells.is_empty()).then(|| {
+            head_cells
+                .iter()
+                .enumerate()
+                .filter(|(idx, cell)| is_column_exported(*idx, cell))
+                .map(|(_, cell)| cell_tag_text(cell))
+                .collect::<Vec<_>>()
+        });
+        let body = rows.map(|row| {
+            data_cells(row)
+                .into_iter()
+                .enumerate()
+                .filter(|(idx, cell)| is_column_exported(*idx, cell))
+                .map(|(_, cell)| cell_tag_text(cell))
+                .collect::<Vec<_>>()
+        });
+        records_to_csv(head.into_iter().chain(body))
+    }
+
+    /// Exports the current contents of the existing table as CSV text. Unlike
+    /// [`DataTable::to_csv`], it sees the values changed by the user and the current selection.
+    pub fn to_csv_existing(id: impl AsRef<str>, options: CsvOptions) -> String {
+        let root = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        let head_cells = select_all(&root, "thead > tr > th")
+            .into_iter()
+            .filter(|cell| !is_control_cell(cell))
+            .collect::<Vec<_>>();
+        let visible_columns: Vec<bool> = head_cells.iter().map(is_element_visible).collect();
+        let is_column_exported = |idx: usize, cell: &Element| {
+            !options.visible_columns_only
+                || (visible_columns.get(idx).copied().unwrap_or(true) && is_element_visible(cell))
+        };
+
+        let rows_selector = if options.selected_rows_only {
+            format!("tbody > tr.mdc-data-table__row.{}", ROW_SELECTED_CLASS)
+        } else {
+            "tbody > tr.mdc-data-table__row".to_string()
+        };
+
+        let head = (!head_cells.is_empty()).then(|| {
+            head_cells
+                .iter()
+                .enumerate()
+                .filter(|(idx, cell)| is_column_exported(*idx, cell))
+                .map(|(_, cell)| cell_element_text(cell))
+                .collect::<Vec<_>>()
+        });
+        let body = select_all(&root, &rows_selector).into_iter().map(|row| {
+            select_all(&row, ":scope > td")
+                .into_iter()
+                .filter(|cell| !is_control_cell(cell))
+                .enumerate()
+                .filter(|(idx, cell)| is_column_exported(*idx, cell))
+                .map(|(_, cell)| cell_element_text(&cell))
+                .collect::<Vec<_>>()
+        });
+        records_to_csv(head.into_iter().chain(body))
+    }
+
+    /// Exports the existing table as CSV and triggers the browser download of it.
+    pub fn download_csv_existing(
+        id: impl AsRef<str>,
+        file_name: impl AsRef<str>,
+        options: CsvOptions,
+    ) -> Result<(), JsValue> {
+        download_csv(file_name, Self::to_csv_existing(id, options))
+    }
+
+    /// Exports the existing table as CSV and copies it to the clipboard.
+    pub fn copy_csv_existing(id: impl AsRef<str>, options: CsvOptions) -> Result<js_sys::Promise, JsValue> {
+        copy_to_clipboard(Self::to_csv_existing(id, options))
+    }
+}
+
+fn data_cells(row: &VTag) -> Vec<&VTag> {
+    (0..row.children_count())
+        .filter_map(|idx| row.get_child(idx).and_then(|cell| cell.root_tag()))
+        .filter(|cell| !cell.is_contains_any_class(&CONTROL_CELL_CLASSES))
+        .collect()
+}
+
+fn is_cell_visible(cell: &VTag) -> bool {
+    cell.attr("hidden").is_none()
+        && !cell
+            .attr("style")
+            .map(|style| style.replace(' ', "").contains("display:none"))
+            .unwrap_or(false)
+}
+
+fn is_row_tag_selected(row: &VTag) -> bool {
+    row.is_contains_class(ROW_SELECTED_CLASS) || row.attr("aria-selected").as_deref() == Some("true")
+}
+
+fn cell_tag_text(cell: &VTag) -> String {
+    match cell.find_child_contains_class(DataTable::CELL_VALUE_CLASS) {
+        Some(value) => html_text(&Html::VTag(Box::new(value.clone()))),
+        None => html_text(&Html::VTag(Box::new(cell.clone()))),
+    }
+}
+
+fn select_all(parent: &Element, selector: &str) -> Vec<Element> {
+    let mut elements = Vec::new();
+    if let Ok(nodes) = parent.query_selector_all(selector) {
+        for idx in 0..nodes.length() {
+            if let Some(element) = nodes.item(idx).and_then(|node| node.dyn_into::<Element>().ok()) {
+                elements.push(element);
+            }
+        }
+    }
+    elements
+}
+
+fn is_control_cell(cell: &Element) -> bool {
+    let classes = cell.class_list();
+    CONTROL_CELL_CLASSES.iter().any(|class| classes.contains(class))
+}
+
+fn is_element_visible(element: &Element) -> bool {
+    element
+        .dyn_ref::<HtmlElement>()
+        .map(|element| !element.hidden() && element.offset_parent().is_some())
+        .unwrap_or(true)
+}
+
+fn cell_element_text(cell: &Element) -> String {
+    let text = match cell.query_selector(&format!(".{}", DataTable::CELL_VALUE_CLASS)) {
+        Ok(Some(value)) => value.text_content(),
+        _ => cell.text_content(),
+    };
+    text.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_field_is_borrowed_without_special_characters() {
+        assert!(matches!(csv_field("Frozen yogurt"), Cow::Borrowed("Frozen yogurt")));
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("  padded  "), "  padded  ");
+    }
+
+    #[test]
+    fn csv_field_quotes_delimiter_and_line_breaks() {
+        assert_eq!(csv_field("4.0, 5.0"), "\"4.0, 5.0\"");
+        assert_eq!(csv_field("first\nsecond"), "\"first\nsecond\"");
+        assert_eq!(csv_field("first\r\nsecond"), "\"first\r\nsecond\"");
+    }
+
+    #[test]
+    fn csv_field_doubles_embedded_quotes() {
+        assert_eq!(csv_field("\""), "\"\"\"\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn records_to_csv_separates_fields_and_records() {
+        let csv = records_to_csv([vec!["Dessert", "Calories"], vec!["Eclair", "262"]]);
+        assert_eq!(csv, "Dessert,Calories\r\nEclair,262\r\n");
+    }
+
+    #[test]
+    fn records_to_csv_escapes_fields() {
+        let csv = records_to_csv([["Cupcake, \"large\"", "line\nbreak", " 305 "]]);
+        assert_eq!(csv, "\"Cupcake, \"\"large\"\"\",\"line\nbreak\", 305 \r\n");
+    }
+
+    #[test]
+    fn records_to_csv_keeps_empty_fields_and_records() {
+        let records: [Vec<&str>; 3] = [vec!["", "", ""], vec![], vec!["a"]];
+        assert_eq!(records_to_csv(records), ",,\r\n\r\na\r\n");
+        assert_eq!(records_to_csv(Vec::<Vec<String>>::new()), "");
+    }
+}
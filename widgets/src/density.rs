@@ -0,0 +1,61 @@
+use yew::Classes;
+
+/// The density scale of the widgets, from `0` (the default size) to `-4` (the most compact). Every
+/// step makes the control 4px smaller.
+///
+/// The density is set to the widget with [`MdcWidget::density`](crate::MdcWidget::density), or to
+/// any container element with [`Density::classes`]. In the latter case all the supported widgets
+/// inside the container are styled with the container density, unless they set their own.
+///
+/// The supported widgets are `TextField`, `Button`, `IconButton`, `Checkbox`, `Radio`,
+/// `DataTable`, `Chip` and `TabBar`. The sizes are styled by the `yew-mdc-widgets.css` stylesheet.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Density(i8);
+
+impl Density {
+    pub const DEFAULT_SCALE: i8 = 0;
+
+    pub const MIN_SCALE: i8 = -4;
+
+    /// Marks the element, which sets the density for itself and the nested widgets.
+    pub const CLASS: &'static str = "yew-mdc-density";
+
+    /// The CSS custom property holding the density scale.
+    pub const SCALE_VAR: &'static str = "--yew-mdc-density-scale";
+
+    /// Creates the density, the scale is clamped to the `-4..=0` range.
+    pub fn new(scale: i8) -> Self {
+        Self(scale.clamp(Self::MIN_SCALE, Self::DEFAULT_SCALE))
+    }
+
+    pub fn scale(&self) -> i8 {
+        self.0
+    }
+
+    /// The class, which sets the density scale, like `yew-mdc-density--2` for the `-2` scale.
+    pub fn scale_class(&self) -> String {
+        Self::scale_class_of(self.0)
+    }
+
+    /// All the density scale classes.
+    pub fn scale_classes() -> impl Iterator<Item = String> {
+        (Self::MIN_SCALE..=Self::DEFAULT_SCALE).map(Self::scale_class_of)
+    }
+
+    /// Returns the classes to set the density to any element.
+    pub fn classes(&self) -> Classes {
+        let mut classes = Classes::from(Self::CLASS);
+        classes.push(self.scale_class());
+        classes
+    }
+
+    fn scale_class_of(scale: i8) -> String {
+        format!("{}-{}", Self::CLASS, scale)
+    }
+}
+
+impl From<i8> for Density {
+    fn from(scale: i8) -> Self {
+        Self::new(scale)
+    }
+}
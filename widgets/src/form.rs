@@ -0,0 +1,579 @@
+use std::collections::BTreeSet;
+use std::mem;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+use yew::html::{onfocusin, onfocusout, onsubmit};
+use yew::{html, Callback, FocusEvent, Html, SubmitEvent, ToHtml};
+
+use crate::dom::existing::JsObjectAccess;
+use crate::dom::{self, JsCast};
+use crate::utils::{mdc_object, VTagExt};
+use crate::web_sys::{Element, HtmlInputElement, HtmlSelectElement};
+use crate::{checkbox, console, radio, switch, text_field, Checkbox, MdcWidget, Radio, Switch, TextField, Validator};
+
+/// The values of the form fields by the field names.
+pub type FormValues = Map<String, Value>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FieldKind {
+    Text,
+    Number,
+    Checkbox,
+    Switch,
+    Radio,
+    Select,
+}
+
+impl FieldKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Number => "number",
+            Self::Checkbox => "checkbox",
+            Self::Switch => "switch",
+            Self::Radio => "radio",
+            Self::Select => "select",
+        }
+    }
+
+    fn parse(kind: &str) -> Option<Self> {
+        match kind {
+            "text" => Some(Self::Text),
+            "number" => Some(Self::Number),
+            "checkbox" => Some(Self::Checkbox),
+            "switch" => Some(Self::Switch),
+            "radio" => Some(Self::Radio),
+            "select" => Some(Self::Select),
+            _ => None,
+        }
+    }
+
+    fn is_text_field(&self) -> bool {
+        matches!(self, Self::Text | Self::Number)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    name: String,
+    validators: Vec<Validator>,
+}
+
+/// Container of the named controls, which collects their values into a serde-deserializable
+/// struct and populates the controls from a serializable one.
+///
+/// The registered controls are `TextField`s (the number input gives a number value), `Checkbox`es
+/// and `Switch`es (bool values), `Radio`s with the same name (the value of the checked radio) and
+/// native `select`s (the selected value).
+#[derive(Debug, Clone)]
+pub struct Form {
+    html: Html,
+    fields: Vec<Field>,
+    on_submit: Option<Callback<FormValues, Result<(), serde_json::Error>>>,
+    on_error: Option<Callback<serde_json::Error>>,
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Form {
+    /// Name of the field, set on the root element of each registered control
+    pub const FIELD_ATTR: &'static str = "data-form-field";
+
+    /// Kind of the field, set on the root element of each registered control
+    pub const FIELD_KIND_ATTR: &'static str = "data-form-field-kind";
+
+    /// Set on the root element of the control which has lost focus at least once
+    pub const TOUCHED_ATTR: &'static str = "data-form-touched";
+
+    /// JSON of the values which the dirty state is compared to
+    pub const INITIAL_VALUES_ATTR: &'static str = "data-form-initial-values";
+
+    pub fn new() -> Self {
+        Self {
+            html: html! { <form novalidate = true></form> },
+            fields: Vec::new(),
+            on_submit: None,
+            on_error: None,
+        }
+    }
+
+    /// Registers the text field by the name and adds it to the form. The validators of the text
+    /// field also run on submit.
+    pub fn text_field(mut self, name: impl Into<String>, mut text_field: TextField) -> Self {
+        let is_number = text_field
+            .input_tag_mut()
+            .and_then(|input| input.attr("type"))
+            .is_some_and(|input_type| input_type == "number");
+        let kind = if is_number { FieldKind::Number } else { FieldKind::Text };
+        let validators = text_field.get_validators().to_vec();
+
+        let name = name.into();
+        mark_field(&mut text_field, &name, kind);
+        self.add_field(name, validators);
+        self.child(text_field)
+    }
+
+    pub fn checkbox(mut self, name: impl Into<String>, mut checkbox: Checkbox) -> Self {
+        let name = name.into();
+        mark_field(&mut checkbox, &name, FieldKind::Checkbox);
+        self.add_field(name, Vec::new());
+        self.child(checkbox)
+    }
+
+    pub fn switch(mut self, name: impl Into<String>, mut switch: Switch) -> Self {
+        let name = name.into();
+        mark_field(&mut switch, &name, FieldKind::Switch);
+        self.add_field(name, Vec::new());
+        self.child(switch)
+    }
+
+    /// Registers the radio by the name of the radio set, the field value is the value of the
+    /// checked radio (see [`Radio::value`]) or `null` if no radio is checked.
+    pub fn radio(mut self, name: impl Into<String>, radio: Radio) -> Self {
+        let name = name.into();
+        let mut radio = radio.name_of_set(name.clone());
+        mark_field(&mut radio, &name, FieldKind::Radio);
+        self.add_field(name, Vec::new());
+        self.child(radio)
+    }
+
+    /// Registers the native `select` element, which is the root of the `select` html or its
+    /// descendant.
+    pub fn select(mut self, name: impl Into<String>, select: impl Into<Html>) -> Self {
+        let name = name.into();
+        let mut select = select.into();
+        match select.root_tag_mut() {
+            Some(root) => {
+                root.set_attr(Self::FIELD_ATTR, name.clone());
+                root.set_attr(Self::FIELD_KIND_ATTR, FieldKind::Select.as_str());
+            },
+            None => console::error!(format!("The root element of the select `{}` must be a tag", name)),
+        }
+        self.add_field(name, Vec::new());
+        self.child(select)
+    }
+
+    /// Adds the validator of the field, which runs on submit. The validator of the non-text
+    /// field gets the string representation of its value: `"true"` or an empty string for the
+    /// bool value and an empty string for `null`.
+    pub fn validator(mut self, name: impl Into<String>, validator: Validator) -> Self {
+        self.add_field(name.into(), vec![validator]);
+        self
+    }
+
+    /// Sets the callback, which is called with the values deserialized into `T` on submit when
+    /// all field validators pass. Use [`FormValues`] as `T` to get the raw values. The values,
+    /// which can not be deserialized, go to [`on_error`](Self::on_error).
+    pub fn on_submit<T: DeserializeOwned + 'static>(mut self, callback: impl Into<Callback<T>>) -> Self {
+        let callback = callback.into();
+        self.on_submit = Some(Callback::from(move |values: FormValues| {
+            serde_json::from_value(Value::Object(values)).map(|data| callback.emit(data))
+        }));
+        self
+    }
+
+    /// Sets the callback, which is called when the submitted values can not be deserialized into
+    /// the type of the [`on_submit`](Self::on_submit) callback. Without it the error is logged.
+    pub fn on_error(mut self, callback: impl Into<Callback<serde_json::Error>>) -> Self {
+        self.on_error = Some(callback.into());
+        self
+    }
+
+    /// Returns the current values of the existing form controls.
+    pub fn values_existing(id: impl AsRef<str>) -> FormValues {
+        let form = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        collect_values(&field_elements(&form))
+    }
+
+    /// Returns the current values of the existing form controls deserialized into `T`.
+    pub fn get_existing<T: DeserializeOwned>(id: impl AsRef<str>) -> Result<T, serde_json::Error> {
+        serde_json::from_value(Value::Object(Self::values_existing(id)))
+    }
+
+    /// Populates the existing form controls from the `data` fields with the same names. The
+    /// populated values become the initial ones, so the form is not dirty and not touched after.
+    pub fn set_existing<T: Serialize>(id: impl AsRef<str>, data: &T) -> Result<(), serde_json::Error> {
+        let values = match serde_json::to_value(data)? {
+            Value::Object(values) => values,
+            _ => return Err(serde::ser::Error::custom("the form data must be serialized into a map")),
+        };
+
+        let form = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        for element in field_elements(&form) {
+            if let Some((name, kind)) = field_name_and_kind(&element) {
+                if let Some(value) = values.get(&name) {
+                    set_field_value(&element, kind, value);
+                }
+            }
+        }
+        mark_pristine(&form);
+        Ok(())
+    }
+
+    /// Returns whether or not the values of the existing form differ from the initial ones.
+    pub fn is_dirty_existing(id: impl AsRef<str>) -> bool {
+        !Self::dirty_fields_existing(id).is_empty()
+    }
+
+    /// Returns the names of the fields whose values differ from the initial ones.
+    pub fn dirty_fields_existing(id: impl AsRef<str>) -> Vec<String> {
+        let form = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        let initial = initial_values(&form);
+        collect_values(&field_elements(&form))
+            .into_iter()
+            .filter_map(|(name, value)| match &initial {
+                Some(initial) if initial.get(&name) != Some(&value) => Some(name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns whether or not any control of the existing form has lost focus.
+    pub fn is_touched_existing(id: impl AsRef<str>) -> bool {
+        !Self::touched_fields_existing(id).is_empty()
+    }
+
+    /// Returns the names of the fields whose controls have lost focus at least once.
+    pub fn touched_fields_existing(id: impl AsRef<str>) -> Vec<String> {
+        let form = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        field_elements(&form)
+            .into_iter()
+            .filter(|element| element.has_attribute(Self::TOUCHED_ATTR))
+            .filter_map(|element| element.get_attribute(Self::FIELD_ATTR))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Makes the current values initial and resets the touched state of the existing form.
+    pub fn mark_pristine_existing(id: impl AsRef<str>) {
+        mark_pristine(&dom::existing::get_element_by_id::<Element>(id.as_ref()));
+    }
+
+    fn add_field(&mut self, name: String, validators: Vec<Validator>) {
+        if let Some(field) = self.fields.iter_mut().find(|field| field.name == name) {
+            field.validators.extend(validators);
+        } else {
+            self.fields.push(Field { name, validators });
+        }
+    }
+
+    fn add_listeners(mut self) -> Self {
+        let fields = Rc::new(mem::take(&mut self.fields));
+        let on_submit = self.on_submit.take();
+        let on_error = self.on_error.take();
+        let root = self.html.root_tag_mut().unwrap(/* root tag already exists */);
+
+        root.add_listener(Rc::new(onsubmit::Wrapper::new(Callback::from(
+            move |event: SubmitEvent| {
+                event.prevent_default();
+                if let Some(form) = event.target().and_then(|target| target.dyn_into::<Element>().ok()) {
+                    let elements = field_elements(&form);
+                    for element in &elements {
+                        let _ = element.set_attribute(Self::TOUCHED_ATTR, "true");
+                    }
+                    if validate_fields(&elements, &fields) {
+                        let result = on_submit
+                            .as_ref()
+                            .map(|on_submit| on_submit.emit(collect_values(&elements)));
+                        match (result, &on_error) {
+                            (Some(Err(err)), Some(on_error)) => on_error.emit(err),
+                            (Some(Err(err)), None) => {
+                                console::error!(format!("Could not deserialize the {} values: {}", Self::NAME, err))
+                            },
+                            _ => (),
+                        }
+                    }
+                }
+            },
+        ))));
+
+        // The values are taken as initial before the first interaction with the form
+        root.add_listener(Rc::new(onfocusin::Wrapper::new(Callback::from(|event: FocusEvent| {
+            if let Some(form) = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|target| target.closest("form").ok().flatten())
+            {
+                if !form.has_attribute(Self::INITIAL_VALUES_ATTR) {
+                    store_initial_values(&form);
+                }
+            }
+        }))));
+
+        root.add_listener(Rc::new(onfocusout::Wrapper::new(Callback::from(
+            |event: FocusEvent| {
+                if let Some(field) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .and_then(|target| target.closest(&format!("[{}]", Self::FIELD_ATTR)).ok().flatten())
+                {
+                    let _ = field.set_attribute(Self::TOUCHED_ATTR, "true");
+                }
+            },
+        ))));
+        self
+    }
+}
+
+fn mark_field(widget: &mut impl MdcWidget, name: &str, kind: FieldKind) {
+    let root = widget.root_tag_mut();
+    root.set_attr(Form::FIELD_ATTR, name.to_string());
+    root.set_attr(Form::FIELD_KIND_ATTR, kind.as_str());
+}
+
+fn field_elements(form: &Element) -> Vec<Element> {
+    let mut elements = Vec::new();
+    if let Ok(nodes) = form.query_selector_all(&format!("[{}]", Form::FIELD_ATTR)) {
+        for idx in 0..nodes.length() {
+            if let Some(element) = nodes.item(idx).and_then(|node| node.dyn_into::<Element>().ok()) {
+                elements.push(element);
+            }
+        }
+    }
+    elements
+}
+
+fn field_name_and_kind(element: &Element) -> Option<(String, FieldKind)> {
+    let name = element.get_attribute(Form::FIELD_ATTR)?;
+    let kind = FieldKind::parse(&element.get_attribute(Form::FIELD_KIND_ATTR)?)?;
+    Some((name, kind))
+}
+
+fn native_input(element: &Element) -> Option<HtmlInputElement> {
+    element
+        .query_selector("input")
+        .ok()
+        .flatten()
+        .and_then(|input| input.dyn_into::<HtmlInputElement>().ok())
+}
+
+fn native_select(element: &Element) -> Option<HtmlSelectElement> {
+    match element.clone().dyn_into::<HtmlSelectElement>() {
+        Ok(select) => Some(select),
+        Err(_) => element
+            .query_selector("select")
+            .ok()
+            .flatten()
+            .and_then(|select| select.dyn_into::<HtmlSelectElement>().ok()),
+    }
+}
+
+fn is_checked(element: &Element, type_name: &str) -> bool {
+    match mdc_object(element, type_name) {
+        Some(object) => object.get("checked").as_bool().unwrap_or_default(),
+        None => native_input(element).is_some_and(|input| input.checked()),
+    }
+}
+
+fn set_checked(element: &Element, type_name: &str, checked: bool) {
+    match mdc_object(element, type_name) {
+        Some(object) => {
+            object.set("checked", checked);
+        },
+        None => {
+            if let Some(input) = native_input(element) {
+                input.set_checked(checked);
+            }
+        },
+    }
+}
+
+fn text_field_value(element: &Element) -> String {
+    match mdc_object(element, text_field::mdc::TYPE_NAME) {
+        Some(object) => object.unchecked_into::<text_field::mdc::TextField>().value(),
+        None => text_field::input_value(element),
+    }
+}
+
+fn number_value(number: f64) -> Value {
+    if number.fract() == 0.0 && number.abs() < i64::MAX as f64 {
+        Value::from(number as i64)
+    } else {
+        Number::from_f64(number).map_or(Value::Null, Value::Number)
+    }
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null | Value::Bool(false) => String::new(),
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// The radio returns `None` if it is not checked.
+fn field_value(element: &Element, kind: FieldKind) -> Option<Value> {
+    let value = match kind {
+        FieldKind::Text => Value::String(text_field_value(element)),
+        FieldKind::Number => {
+            let value = text_field_value(element);
+            let value = value.trim();
+            if value.is_empty() {
+                Value::Null
+            } else {
+                value.parse().map_or_else(|_| Value::String(value.into()), number_value)
+            }
+        },
+        FieldKind::Checkbox => Value::Bool(is_checked(element, checkbox::mdc::TYPE_NAME)),
+        FieldKind::Switch => Value::Bool(match mdc_object(element, switch::mdc::TYPE_NAME) {
+            Some(object) => object.get("selected").as_bool().unwrap_or_default(),
+            None => element.class_list().contains(Switch::SELECTED_CLASS),
+        }),
+        FieldKind::Radio => {
+            if !is_checked(element, radio::mdc::TYPE_NAME) {
+                return None;
+            }
+            Value::String(native_input(element).map(|input| input.value()).unwrap_or_default())
+        },
+        FieldKind::Select => Value::String(native_select(element).map(|select| select.value()).unwrap_or_default()),
+    };
+    Some(value)
+}
+
+fn set_field_value(element: &Element, kind: FieldKind, value: &Value) {
+    match kind {
+        FieldKind::Text | FieldKind::Number => {
+            let value = value_to_string(value);
+            match mdc_object(element, text_field::mdc::TYPE_NAME) {
+                Some(object) => object.unchecked_into::<text_field::mdc::TextField>().set_value(&value),
+                None => text_field::set_input_value(element, &value),
+            }
+        },
+        FieldKind::Checkbox => set_checked(element, checkbox::mdc::TYPE_NAME, value.as_bool().unwrap_or_default()),
+        FieldKind::Switch => {
+            let selected = value.as_bool().unwrap_or_default();
+            match mdc_object(element, switch::mdc::TYPE_NAME) {
+                Some(object) => {
+                    object.set("selected", selected);
+                },
+                None => {
+                    let classes = element.class_list();
+                    let _ = classes.toggle_with_force(Switch::SELECTED_CLASS, selected);
+                    let _ = classes.toggle_with_force(Switch::UNSELECTED_CLASS, !selected);
+                    let _ = element.set_attribute("aria-checked", if selected { "true" } else { "false" });
+                },
+            }
+        },
+        FieldKind::Radio => {
+            let radio_value = native_input(element).map(|input| input.value());
+            let checked = radio_value.as_deref() == Some(value_to_string(value).as_str());
+            set_checked(element, radio::mdc::TYPE_NAME, checked);
+        },
+        FieldKind::Select => {
+            if let Some(select) = native_select(element) {
+                select.set_value(&value_to_string(value));
+            }
+        },
+    }
+}
+
+fn collect_values(elements: &[Element]) -> FormValues {
+    let mut values = FormValues::new();
+    for element in elements {
+        if let Some((name, kind)) = field_name_and_kind(element) {
+            match field_value(element, kind) {
+                Some(value) => {
+                    values.insert(name, value);
+                },
+                None => {
+                    values.entry(name).or_insert(Value::Null);
+                },
+            }
+        }
+    }
+    values
+}
+
+fn validate_fields(elements: &[Element], fields: &[Field]) -> bool {
+    let values = collect_values(elements);
+    let mut is_valid = true;
+
+    for field in fields.iter().filter(|field| !field.validators.is_empty()) {
+        let value = values.get(&field.name).map(value_to_string).unwrap_or_default();
+        let result = Validator::validate_all(&field.validators, &value);
+        is_valid &= result.is_ok();
+
+        let field_elements = elements
+            .iter()
+            .filter_map(|element| field_name_and_kind(element).map(|(name, kind)| (element, name, kind)))
+            .filter(|(_, name, _)| *name == field.name);
+        for (element, _, kind) in field_elements {
+            if kind.is_text_field() {
+                let _ = text_field::validate_element(element, &field.validators);
+            } else {
+                let _ = element.set_attribute("aria-invalid", if result.is_ok() { "false" } else { "true" });
+            }
+        }
+    }
+    is_valid
+}
+
+fn initial_values(form: &Element) -> Option<FormValues> {
+    form.get_attribute(Form::INITIAL_VALUES_ATTR)
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+fn store_initial_values(form: &Element) {
+    let values = collect_values(&field_elements(form));
+    if let Ok(json) = serde_json::to_string(&values) {
+        let _ = form.set_attribute(Form::INITIAL_VALUES_ATTR, &json);
+    }
+}
+
+fn mark_pristine(form: &Element) {
+    for element in field_elements(form) {
+        let _ = element.remove_attribute(Form::TOUCHED_ATTR);
+    }
+    store_initial_values(form);
+}
+
+impl MdcWidget for Form {
+    const NAME: &'static str = stringify!(Form);
+
+    fn html(&self) -> &Html {
+        &self.html
+    }
+
+    fn html_mut(&mut self) -> &mut Html {
+        &mut self.html
+    }
+}
+
+impl Deref for Form {
+    type Target = Html;
+
+    fn deref(&self) -> &Self::Target {
+        &self.html
+    }
+}
+
+impl DerefMut for Form {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.html
+    }
+}
+
+impl From<Form> for Html {
+    fn from(widget: Form) -> Self {
+        widget.add_listeners().html
+    }
+}
+
+impl ToHtml for Form {
+    fn to_html(&self) -> Html {
+        self.clone().into()
+    }
+
+    fn into_html(self) -> Html {
+        self.into()
+    }
+}
@@ -5,10 +5,11 @@ use const_format::concatcp;
 use yew::html::onclick;
 use yew::{html, Callback, Html, MouseEvent, ToHtml};
 
+use crate::binding::push_after_render;
 use crate::dom::existing::JsObjectAccess;
 use crate::dom::{self, JsCast};
-use crate::utils::VTagExt;
-use crate::{ripple, CustomEvent, Element, MdcWidget, AUTO_INIT_ATTR, MATERIAL_ICONS_CLASS};
+use crate::utils::{mdc_object, VTagExt};
+use crate::{ripple, Binding, CustomEvent, Element, MdcWidget, AUTO_INIT_ATTR, MATERIAL_ICONS_CLASS};
 
 pub mod mdc {
     use wasm_bindgen::prelude::*;
@@ -141,6 +142,31 @@ impl IconButton {
         self.listener(Rc::new(onclick::Wrapper::new(callback.into())))
     }
 
+    /// Binds the "on" state of the toggle to the model.
+    pub fn bind(mut self, binding: impl Into<Binding<bool>>) -> Self {
+        let (is_on, on_change) = binding.into().into_parts();
+        self.enable_toggle();
+        let root = self.root_tag_mut();
+        if is_on {
+            root.add_class_if_needed(Self::ON_CLASS);
+        } else {
+            root.remove_class(Self::ON_CLASS);
+        }
+        root.set_attr("aria-pressed", if is_on { "true" } else { "false" });
+        push_after_render(root, move |root| {
+            if let Some(toggle) = mdc_object(root, mdc::TYPE_NAME) {
+                let toggle = toggle.unchecked_into::<mdc::IconButtonToggle>();
+                if toggle.on() != is_on {
+                    toggle.set_on(is_on);
+                }
+            }
+        });
+        self.on_change(move |event: CustomEvent| {
+            let is_on = event.detail().get("isOn").as_bool().unwrap_or_default();
+            on_change.emit(is_on);
+        })
+    }
+
     fn enable_toggle(&mut self) {
         if !self.is_toggle {
             let root = self.root_tag_mut();
@@ -1,45 +1,60 @@
 #![recursion_limit = "256"]
 
-pub use {gloo, gloo_console as console, js_sys, wasm_bindgen, wasm_dom as dom, web_sys, yew};
+pub use {gloo, gloo_console as console, js_sys, wasm_bindgen, wasm_bindgen_futures, wasm_dom as dom, web_sys, yew};
 
+pub use self::autocomplete::*;
+pub use self::binding::*;
 pub use self::button::*;
 pub use self::card::*;
 pub use self::checkbox::*;
 pub use self::chip::*;
 pub use self::data_table::*;
+pub use self::density::*;
 pub use self::dialog::*;
 pub use self::drawer::*;
 pub use self::fab::*;
 pub use self::floating_label::*;
+pub use self::form::*;
 pub use self::icon_button::*;
 pub use self::linear_progress::*;
 pub use self::list::*;
 pub use self::listeners::*;
+pub use self::mask::*;
 pub use self::mdc::auto_init;
 pub use self::menu::*;
+pub use self::menu_surface::*;
 pub use self::radio::*;
 pub use self::snackbar::*;
 pub use self::switch::*;
 pub use self::tab::*;
 pub use self::text_field::*;
 pub use self::top_app_bar::*;
+pub use self::tree_view::*;
+pub use self::validation::*;
+pub use self::virtual_list::*;
 pub use self::widget::{MdcObject, MdcWidget};
 
+pub mod autocomplete;
+pub mod binding;
 pub mod button;
 pub mod card;
 pub mod checkbox;
 pub mod chip;
 pub mod data_table;
+pub mod density;
 pub mod dialog;
 pub mod drawer;
 pub mod fab;
 pub mod floating_label;
+pub mod form;
 pub mod icon_button;
 pub mod line_ripple;
 pub mod linear_progress;
 pub mod list;
 pub mod listeners;
+pub mod mask;
 pub mod menu;
+pub mod menu_surface;
 pub mod notched_outline;
 pub mod radio;
 pub mod ripple;
@@ -48,7 +63,10 @@ pub mod switch;
 pub mod tab;
 pub mod text_field;
 pub mod top_app_bar;
+pub mod tree_view;
 pub mod utils;
+pub mod validation;
+pub mod virtual_list;
 pub mod widget;
 
 pub mod mdc {
@@ -1,12 +1,22 @@
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
 
+use const_format::concatcp;
 use yew::html::onclick;
-use yew::virtual_dom::AttrValue;
+use yew::virtual_dom::{AttrValue, VTag};
 use yew::{classes, html, Callback, Html, MouseEvent, ToHtml};
 
-use crate::utils::{ManageChildren, VTagExt};
-use crate::{ripple, MdcWidget, AUTO_INIT_ATTR};
+pub use self::group::*;
+use crate::binding::push_after_render;
+use crate::dom::existing::JsObjectAccess;
+use crate::dom::{self, JsCast, JsValue};
+use crate::utils::{mdc_object, ManageChildren, VTagExt};
+use crate::{
+    console, js_sys, ripple, Checkbox, CustomEvent, Element, ItemHeight, MdcWidget, Radio, VirtualList, AUTO_INIT_ATTR,
+};
+
+pub mod group;
+mod sortable;
 
 pub mod mdc {
     use wasm_bindgen::prelude::*;
@@ -55,28 +65,91 @@ pub mod mdc {
         pub fn selected_index(this: &List) -> ListIndex;
 
         /// Sets the selection state to given index or list of indexes if it is checkbox based list.
-        #[wasm_bindgen(method, setter = selectedIndex)]
-        pub fn set_selected_index(this: &List, value: &ListIndex);
+        #[wasm_bindgen(method, catch, setter = selectedIndex)]
+        pub fn set_selected_index(this: &List, value: &ListIndex) -> Result<(), JsValue>;
 
         /// Recalculates layout and orientation.
         #[wasm_bindgen(method)]
         pub fn layout(this: &List);
 
         /// Fetches the primary text in the given element.
-        #[wasm_bindgen(method)]
+        #[wasm_bindgen(method, js_name = getPrimaryText)]
         pub fn get_primary_text(this: &List, item: &Element) -> String;
 
         /// Initialize `selectedIndex` value based on pre-selected checkbox list items,
         /// single selection or radio.
-        #[wasm_bindgen(method)]
+        #[wasm_bindgen(method, js_name = initializeListType)]
         pub fn initialize_list_type(this: &List);
 
         /// Updates the list item at `itemIndex` to the desired `isEnabled` state.
-        #[wasm_bindgen(method)]
+        #[wasm_bindgen(method, js_name = setEnabled)]
         pub fn set_enabled(this: &List, item_index: usize, is_enabled: bool);
     }
 }
 
+/// The selection state of the list: the index of the selected item for the single selection and
+/// radio lists, or the indexes of the checked items for the checkbox lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ListSelection {
+    #[default]
+    None,
+    Single(usize),
+    Multi(Vec<usize>),
+}
+
+impl ListSelection {
+    pub fn is_selected(&self, index: usize) -> bool {
+        match self {
+            Self::None => false,
+            Self::Single(selected) => *selected == index,
+            Self::Multi(selected) => selected.contains(&index),
+        }
+    }
+
+    pub fn indexes(&self) -> Vec<usize> {
+        match self {
+            Self::None => Vec::new(),
+            Self::Single(selected) => vec![*selected],
+            Self::Multi(selected) => selected.clone(),
+        }
+    }
+}
+
+impl From<mdc::ListIndex> for ListSelection {
+    fn from(index: mdc::ListIndex) -> Self {
+        let index = JsValue::from(index);
+        if let Some(indexes) = index.dyn_ref::<js_sys::Array>() {
+            Self::Multi(
+                indexes
+                    .iter()
+                    .filter_map(|index| index.as_f64())
+                    .map(|index| index as usize)
+                    .collect(),
+            )
+        } else {
+            match index.as_f64() {
+                Some(index) if index >= 0.0 => Self::Single(index as usize),
+                _ => Self::None,
+            }
+        }
+    }
+}
+
+impl From<ListSelection> for mdc::ListIndex {
+    fn from(selection: ListSelection) -> Self {
+        let index = match selection {
+            ListSelection::None => JsValue::from(-1),
+            ListSelection::Single(index) => JsValue::from(index as u32),
+            ListSelection::Multi(indexes) => indexes
+                .into_iter()
+                .map(|index| JsValue::from(index as u32))
+                .collect::<js_sys::Array>()
+                .into(),
+        };
+        index.unchecked_into()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ListItem {
     html: Html,
@@ -90,7 +163,14 @@ impl ListItem {
     pub const RIPPLE_CLASS: &'static str = "mdc-deprecated-list-item__ripple";
     pub const SECONDARY_TEXT_ITEM_CLASS: &'static str = "mdc-deprecated-list-item__secondary-text";
     pub const SELECTION_CLASS: &'static str = "mdc-deprecated-list-item--selected";
+    pub const ACTIVATED_CLASS: &'static str = "mdc-deprecated-list-item--activated";
     pub const TEXT_ITEM_CLASS: &'static str = "mdc-deprecated-list-item__text";
+    pub const VALUE_ATTR: &'static str = "data-value";
+    pub const DRAG_HANDLE_CLASS: &'static str = "yew-mdc-list-item__drag-handle";
+    pub const DRAGGING_CLASS: &'static str = "yew-mdc-list-item--dragging";
+    pub const GRABBED_CLASS: &'static str = "yew-mdc-list-item--grabbed";
+    pub const DROP_BEFORE_CLASS: &'static str = "yew-mdc-list-item--drop-before";
+    pub const DROP_AFTER_CLASS: &'static str = "yew-mdc-list-item--drop-after";
 
     pub fn simple() -> Self {
         Self {
@@ -168,6 +248,11 @@ impl ListItem {
         self
     }
 
+    /// Sets the value, which identifies the item in the selection events.
+    pub fn value(self, value: impl Into<String>) -> Self {
+        self.attr(Self::VALUE_ATTR, value)
+    }
+
     pub fn selected(mut self, selected: bool) -> Self {
         let is_already_selected = self.html.is_contains_class(Self::SELECTION_CLASS);
 
@@ -235,6 +320,40 @@ impl ListItem {
         self
     }
 
+    /// Adds the checkbox to the tile of the checkbox list item. The checkbox should have ID to be
+    /// labeled by the item [`label`](Self::label).
+    pub fn checkbox(self, checkbox: Checkbox) -> Self {
+        self.selection_control(checkbox.into(), "checkbox")
+    }
+
+    /// Adds the radio button to the tile of the radio list item. The radio button should have ID to
+    /// be labeled by the item [`label`](Self::label).
+    pub fn radio(self, radio: Radio) -> Self {
+        self.selection_control(radio.into(), "radio")
+    }
+
+    /// Adds the trailing handle, which drags the item of the sortable list. The item is grabbed by
+    /// the Space or Enter keys on the focused handle as well, see [`List::sortable`].
+    pub fn drag_handle(mut self) -> Self {
+        self.root_tag_mut().add_child(html! {
+            <button type = "button" class = { classes!(Self::LAST_TILE_CLASS, Self::DRAG_HANDLE_CLASS, "material-icons") }
+                    aria-label = "Reorder" aria-pressed = "false">
+                { "drag_indicator" }
+            </button>
+        });
+        self
+    }
+
+    fn selection_control(self, control: Html, role: &'static str) -> Self {
+        let checked = control
+            .find_child_tag_recursively("input")
+            .and_then(VTag::checked)
+            .unwrap_or(false);
+        self.tile(control)
+            .attr("role", role)
+            .attr("aria-checked", checked.to_string())
+    }
+
     pub fn on_click(self, callback: impl Into<Callback<MouseEvent>>) -> Self {
         self.listener(Rc::new(onclick::Wrapper::new(callback.into())))
     }
@@ -288,6 +407,8 @@ impl List {
     pub const DIVIDER_INSET_TRAILING_CLASS: &'static str = "mdc-deprecated-ist-divider--inset-trailing";
     pub const DIVIDER_INSET_PADDING_CLASS: &'static str = "mdc-deprecated-list-divider--inset-padding";
     pub const GROUP_SUBHEADER_CLASS: &'static str = "mdc-deprecated-list-group__subheader";
+    pub const SORTABLE_CLASS: &'static str = "yew-mdc-list--sortable";
+    pub const ACTION_EVENT: &'static str = concatcp!(mdc::TYPE_NAME, ":action");
     const LIST_VAR_NAME: &'static str = "list";
 
     pub fn simple_ul() -> Self {
@@ -319,11 +440,210 @@ impl List {
         self.add_script_statement(statement)
     }
 
+    /// Makes the list a multi-selection list of the items with checkboxes, see
+    /// [`ListItem::checkbox`].
+    pub fn checkbox_list(self) -> Self {
+        self.attr("role", "group")
+    }
+
+    /// Makes the list a single selection list of the items with radio buttons, see
+    /// [`ListItem::radio`].
+    pub fn radio_list(self) -> Self {
+        self.attr("role", "radiogroup")
+    }
+
+    /// Emits the index of the item, when the item is activated by a click or by the Enter or
+    /// Space keys.
+    pub fn on_action(self, callback: impl Into<Callback<usize>>) -> Self {
+        let callback = callback.into();
+        self.on_item_action(move |index, _| callback.emit(index))
+    }
+
+    /// Emits the selection state of the list, see [`selection_existing`](Self::selection_existing),
+    /// when the item is activated by the user.
+    pub fn on_selection_change(self, callback: impl Into<Callback<ListSelection>>) -> Self {
+        let callback = callback.into();
+        self.on_item_action(move |_, list| callback.emit(list.selected_index().into()))
+    }
+
+    /// Runs `handle` with the index of the activated item and the MDC object of the list on the
+    /// MDC action event.
+    fn on_item_action(self, handle: impl Fn(usize, &mdc::List) + 'static) -> Self {
+        self.on_event(Self::ACTION_EVENT, move |event: CustomEvent| {
+            let index = event.detail().get("index").as_f64();
+            let list = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|root| mdc_object(&root, mdc::TYPE_NAME));
+            if let (Some(index), Some(list)) = (index, list) {
+                handle(index as usize, list.unchecked_ref());
+            }
+        })
+    }
+
+    /// Makes the items reorderable by their drag handles, see [`ListItem::drag_handle`]. The item is
+    /// dragged by the pointer, or grabbed by the Space key on the handle, moved by the arrow keys
+    /// and dropped by the Space key again. The placeholder shows the position of the drop.
+    ///
+    /// The list is not reordered by itself: handle [`on_reorder`](Self::on_reorder) to reorder
+    /// the items in the model.
+    pub fn sortable(mut self) -> Self {
+        let root = self.root_tag_mut();
+        root.add_class_if_needed(Self::SORTABLE_CLASS);
+        for listener in sortable::listeners() {
+            root.add_listener(listener);
+        }
+        self
+    }
+
+    /// Emits the item index before and after the move, when the item of the sortable list is
+    /// dropped at the new position.
+    pub fn on_reorder(self, callback: impl Into<Callback<(usize, usize)>>) -> Self {
+        let callback = callback.into();
+        self.on_event(sortable::REORDER_EVENT, move |event: CustomEvent| {
+            let detail = event.detail();
+            if let (Some(from), Some(to)) = (detail.get("from").as_f64(), detail.get("to").as_f64()) {
+                callback.emit((from as usize, to as usize));
+            }
+        })
+    }
+
+    /// Returns the selection state of the existing list.
+    pub fn selection_existing(id: impl AsRef<str>) -> ListSelection {
+        Self::existing(id.as_ref())
+            .map(|list| list.selected_index().into())
+            .unwrap_or_default()
+    }
+
+    /// Sets the selection state of the existing list. The checkbox lists take
+    /// [`ListSelection::Multi`] only, [`ListSelection::None`] unchecks all their items.
+    pub fn set_selection_existing(id: impl AsRef<str>, selection: ListSelection) {
+        let id = id.as_ref();
+        if let Some(list) = Self::existing(id) {
+            let is_checkbox_list = JsValue::from(list.selected_index()).is_array();
+            let selection = match selection {
+                ListSelection::None if is_checkbox_list => ListSelection::Multi(Vec::new()),
+                selection => selection,
+            };
+            if let Err(err) = list.set_selected_index(&selection.clone().into()) {
+                console::error!(format!(
+                    "The selection {:?} is not valid for the {} '{}'",
+                    selection,
+                    Self::NAME,
+                    id
+                ));
+                console::error!(err);
+            }
+        }
+    }
+
+    /// Sets the selected item of the existing single selection or radio list.
+    pub fn set_selected_index_existing(id: impl AsRef<str>, index: usize) {
+        Self::set_selection_existing(id, ListSelection::Single(index));
+    }
+
+    /// Enables or disables the item at the index of the existing list.
+    pub fn set_enabled_existing(id: impl AsRef<str>, index: usize, enabled: bool) {
+        if let Some(list) = Self::existing(id.as_ref()) {
+            list.set_enabled(index, enabled);
+        }
+    }
+
+    /// Recalculates the layout and the orientation of the existing list, for example after the
+    /// items are added.
+    pub fn layout_existing(id: impl AsRef<str>) {
+        if let Some(list) = Self::existing(id.as_ref()) {
+            list.layout();
+        }
+    }
+
+    /// Returns the primary text of the item at the index of the existing list.
+    pub fn primary_text_existing(id: impl AsRef<str>, index: usize) -> Option<String> {
+        let list = Self::existing(id.as_ref())?;
+        let item = list.list_elements().into_iter().nth(index)?;
+        Some(list.get_primary_text(&item))
+    }
+
+    /// Returns all item elements of the existing list, including the disabled items.
+    pub fn items_existing(id: impl AsRef<str>) -> Vec<Element> {
+        Self::existing(id.as_ref())
+            .map(|list| list.list_elements())
+            .unwrap_or_default()
+    }
+
+    /// Sets the orientation of the existing list, see [`vertical`](Self::vertical).
+    pub fn set_vertical_existing(id: impl AsRef<str>, vertical: bool) {
+        if let Some(list) = Self::existing(id.as_ref()) {
+            list.set_vertical(vertical);
+        }
+    }
+
+    /// Sets whether the focus wraps around the ends of the existing list.
+    pub fn set_wrap_focus_existing(id: impl AsRef<str>, wrap_focus: bool) {
+        if let Some(list) = Self::existing(id.as_ref()) {
+            list.set_wrap_focus(wrap_focus);
+        }
+    }
+
+    /// Enables or disables the typeahead of the existing list, see [`typeahead`](Self::typeahead).
+    pub fn set_typeahead_existing(id: impl AsRef<str>, typeahead: bool) {
+        if let Some(list) = Self::existing(id.as_ref()) {
+            list.set_has_typeahead(typeahead);
+        }
+    }
+
+    /// Returns whether the typeahead of the existing list is matching the typed prefix.
+    pub fn typeahead_in_progress_existing(id: impl AsRef<str>) -> bool {
+        Self::existing(id.as_ref()).is_some_and(|list| list.typeahead_in_progress())
+    }
+
+    fn existing(id: &str) -> Option<mdc::List> {
+        let list = dom::existing::get_element_by_id::<Element>(id);
+        let list = mdc_object(&list, mdc::TYPE_NAME).map(JsCast::unchecked_into);
+        if list.is_none() {
+            console::error!(format!("The {} with ID '{}' is not initialized", Self::NAME, id));
+        }
+        list
+    }
+
     pub fn wrap_focus(self) -> Self {
         let statement = format!("{}.{}.wrapFocus = true;", Self::LIST_VAR_NAME, mdc::TYPE_NAME);
         self.add_script_statement(statement)
     }
 
+    /// Sets the orientation of the list: the Up and Down arrow keys move the focus in the vertical
+    /// list, and the Left and Right arrow keys move it in the horizontal one. The lists are vertical
+    /// by default. The orientation is applied to the MDC list after the render, see
+    /// [`set_vertical_existing`](Self::set_vertical_existing) to change it later.
+    pub fn vertical(self, vertical: bool) -> Self {
+        let orientation = if vertical { "vertical" } else { "horizontal" };
+        self.attr("aria-orientation", orientation)
+            .after_init("orientation", move |list| list.set_vertical(vertical))
+    }
+
+    /// Enables or disables the typeahead: typing the characters focuses the next item, which
+    /// primary text starts with them. The typeahead is applied to the MDC list after the render,
+    /// see [`set_typeahead_existing`](Self::set_typeahead_existing) to change it later.
+    pub fn typeahead(self, typeahead: bool) -> Self {
+        self.after_init("typeahead", move |list| list.set_has_typeahead(typeahead))
+    }
+
+    /// Runs `init` with the MDC object of the list after the list is rendered and initialized, see
+    /// [`push_after_render`]. Logs the error if the list has no MDC object by then, like the simple
+    /// lists, which are not auto-initialized.
+    fn after_init(self, setting: &'static str, init: impl FnOnce(&mdc::List) + 'static) -> Self {
+        push_after_render(self.root_tag(), move |root| match mdc_object(root, mdc::TYPE_NAME) {
+            Some(list) => init(list.unchecked_ref()),
+            None => console::error!(format!(
+                "The {} '{}' is not initialized, its {} is not applied",
+                Self::NAME,
+                root.id(),
+                setting
+            )),
+        });
+        self
+    }
+
     pub fn add_script_statement(mut self, statement: String) -> Self {
         if self.html.find_child_tag("script").is_some() {
             self.html.add_child_script_statement(statement);
@@ -461,6 +781,27 @@ impl List {
         self.item(subheader)
     }
 
+    /// Turns the list into the virtual list of the item count, which renders only the visible items
+    /// with the render function.
+    pub fn virtualized<F, I>(self, item_count: usize, item_height: ItemHeight, render_item: F) -> VirtualList
+    where
+        F: Fn(usize) -> I + 'static,
+        I: Into<Html>,
+    {
+        let mut list = self.markup_only();
+        let root = list.root_tag_mut();
+        root.remove_attr_or_prop(AUTO_INIT_ATTR);
+        if root.attr("role").is_none() {
+            root.set_attr("role", "listbox");
+        }
+        VirtualList::new(
+            list.html,
+            item_count,
+            item_height,
+            Rc::new(move |idx| render_item(idx).into()),
+        )
+    }
+
     pub fn markup_only(mut self) -> Self {
         if let Html::VList(mut list) = self.html {
             self.html = list.remove(0);
@@ -0,0 +1,306 @@
+use std::fmt::Display;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+use const_format::concatcp;
+use yew::html::onclick;
+use yew::virtual_dom::{Key, VTag};
+use yew::{html, Callback, Html, MouseEvent, ToHtml};
+
+use super::List;
+use crate::dom::existing::JsObjectAccess;
+use crate::dom::{JsCast, JsValue};
+use crate::utils::{emit_custom_event, ManageChildren, VTagExt};
+use crate::{CustomEvent, Element, MdcWidget, MATERIAL_ICONS_CLASS};
+
+/// The group, which is expanded or collapsed by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListGroupToggle {
+    pub key: String,
+    pub expanded: bool,
+}
+
+/// The lists under the subheaders, optionally sticky and collapsible.
+#[derive(Debug, Clone)]
+pub struct ListGroup {
+    html: Html,
+    keys: Vec<String>,
+    collapsed_keys: Vec<String>,
+    is_collapsible: bool,
+    has_index_bar: bool,
+}
+
+impl Default for ListGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ListGroup {
+    pub const CLASS: &'static str = "mdc-deprecated-list-group";
+    pub const SUBHEADER_CLASS: &'static str = List::GROUP_SUBHEADER_CLASS;
+    pub const STICKY_CLASS: &'static str = "yew-mdc-list-group--sticky";
+    pub const INDEXED_CLASS: &'static str = "yew-mdc-list-group--indexed";
+    pub const TOGGLE_CLASS: &'static str = "yew-mdc-list-group__toggle";
+    pub const INDEX_BAR_CLASS: &'static str = "yew-mdc-list-group__index";
+    pub const KEY_ATTR: &'static str = "data-group-key";
+    pub const JUMP_ATTR: &'static str = "data-group-jump";
+
+    pub const TOGGLE_EVENT: &'static str = "ListGroup:toggle";
+
+    pub fn new() -> Self {
+        let group = Self {
+            html: html! { <div class = { Self::CLASS }></div> },
+            keys: Vec::new(),
+            collapsed_keys: Vec::new(),
+            is_collapsible: false,
+            has_index_bar: false,
+        };
+        group.listener(Rc::new(onclick::Wrapper::new(Callback::from(on_click))))
+    }
+
+    /// Groups the items by the keys, like the first letters of the alphabetical contact list. The
+    /// keys are shown as the subheaders, the groups go in the order of the first item of each key.
+    pub fn from_items<K, I>(items: impl IntoIterator<Item = (K, I)>) -> Self
+    where
+        K: Display + PartialEq,
+        I: Into<Html>,
+    {
+        let mut groups: Vec<(K, Vec<I>)> = Vec::new();
+        for (key, item) in items {
+            match groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+                Some((_, group_items)) => group_items.push(item),
+                None => groups.push((key, vec![item])),
+            }
+        }
+        groups.into_iter().fold(Self::new(), |group, (key, items)| {
+            group.keyed_group(key.to_string(), key.to_string(), List::ul().items(items))
+        })
+    }
+
+    /// Adds the list under the subheader. The group is identified by its position in the events.
+    pub fn group(self, header: impl Into<Html>, list: List) -> Self {
+        let key = self.keys.len().to_string();
+        self.keyed_group(key, header, list)
+    }
+
+    /// Adds the list under the subheader. The group is identified by the key in the events, and it
+    /// must be unique in the list group.
+    pub fn keyed_group(mut self, key: impl Into<String>, header: impl Into<Html>, list: List) -> Self {
+        let key = key.into();
+        let mut list = Html::from(list);
+        if let Some(list) = list.root_tag_mut() {
+            list.key = Some(Key::from(format!("list-{}", key)));
+        }
+
+        let mut header = html! {
+            <h3 class = { Self::SUBHEADER_CLASS }>{ header.into() }</h3>
+        };
+        if let Some(header) = header.root_tag_mut() {
+            header.key = Some(Key::from(format!("header-{}", key)));
+            header.set_attr(Self::KEY_ATTR, key.clone());
+        }
+
+        let root = self.root_tag_mut();
+        root.add_child(header);
+        root.add_child(list);
+        self.keys.push(key);
+        self
+    }
+
+    /// Keeps the subheader of the scrolled group at the top of the scrolling container.
+    pub fn sticky_headers(self) -> Self {
+        self.class(Self::STICKY_CLASS)
+    }
+
+    /// Makes the groups expandable and collapsible by their subheaders.
+    pub fn collapsible(mut self) -> Self {
+        self.is_collapsible = true;
+        self
+    }
+
+    /// Collapses the group with the key, the groups become collapsible. The group can be added
+    /// before or after this call.
+    pub fn collapsed(mut self, key: impl Into<String>) -> Self {
+        self.is_collapsible = true;
+        self.collapsed_keys.push(key.into());
+        self
+    }
+
+    /// Adds the bar of the group keys, which scrolls the list to the group.
+    pub fn index_bar(mut self) -> Self {
+        self.has_index_bar = true;
+        self.class(Self::INDEXED_CLASS)
+    }
+
+    /// Emits when the group is expanded or collapsed by the user.
+    pub fn on_toggle(self, callback: impl Into<Callback<ListGroupToggle>>) -> Self {
+        let callback = callback.into();
+        self.on_event(Self::TOGGLE_EVENT, move |event: CustomEvent| {
+            let detail = event.detail();
+            if let Some(key) = detail.get("key").as_string() {
+                callback.emit(ListGroupToggle {
+                    key,
+                    expanded: detail.get("expanded").is_truthy(),
+                });
+            }
+        })
+    }
+}
+
+fn on_click(event: MouseEvent) {
+    let Some(target) = event.target().and_then(|target| target.dyn_into::<Element>().ok()) else {
+        return;
+    };
+    let group = target.closest(concatcp!(".", ListGroup::CLASS)).ok().flatten();
+    let Some(group) = group else {
+        return;
+    };
+
+    if let Ok(Some(toggle)) = target.closest(concatcp!(".", ListGroup::TOGGLE_CLASS)) {
+        let expanded = toggle.get_attribute("aria-expanded").as_deref() != Some("true");
+        if let Some(header) = toggle
+            .closest(concatcp!(".", ListGroup::SUBHEADER_CLASS))
+            .ok()
+            .flatten()
+        {
+            set_expanded(&group, &header, expanded);
+        }
+    } else if let Ok(Some(jump)) = target.closest(concatcp!("[", ListGroup::JUMP_ATTR, "]")) {
+        event.prevent_default();
+        let key = jump.get_attribute(ListGroup::JUMP_ATTR).unwrap_or_default();
+        if let Some(header) = find_header(&group, &key) {
+            set_expanded(&group, &header, true);
+            header.scroll_into_view_with_bool(true);
+        }
+    }
+}
+
+fn set_expanded(group: &Element, header: &Element, expanded: bool) {
+    let Some(list) = header.next_element_sibling() else {
+        return;
+    };
+    if list.has_attribute("hidden") != expanded {
+        return;
+    }
+    if expanded {
+        let _ = list.remove_attribute("hidden");
+    } else {
+        let _ = list.set_attribute("hidden", "hidden");
+    }
+    if let Ok(Some(toggle)) = header.query_selector(concatcp!(".", ListGroup::TOGGLE_CLASS)) {
+        let _ = toggle.set_attribute("aria-expanded", &expanded.to_string());
+    }
+
+    let key = header.get_attribute(ListGroup::KEY_ATTR).unwrap_or_default();
+    emit_custom_event(group, ListGroup::TOGGLE_EVENT, &[
+        ("key", JsValue::from_str(&key)),
+        ("expanded", JsValue::from(expanded)),
+    ]);
+}
+
+fn find_header(group: &Element, key: &str) -> Option<Element> {
+    let headers = group
+        .query_selector_all(concatcp!("[", ListGroup::KEY_ATTR, "]"))
+        .ok()?;
+    (0..headers.length())
+        .filter_map(|idx| headers.get(idx))
+        .filter_map(|header| header.dyn_into::<Element>().ok())
+        .find(|header| header.get_attribute(ListGroup::KEY_ATTR).as_deref() == Some(key))
+}
+
+/// Wraps the subheader content into the button, which expands and collapses the next list.
+fn make_collapsible(header: &mut VTag, is_expanded: bool) {
+    let content = header.children_mut().map(std::mem::take).unwrap_or_default();
+    header.add_child(html! {
+        <button type = "button" class = { ListGroup::TOGGLE_CLASS } aria-expanded = { is_expanded.to_string() }>
+            <span class = { MATERIAL_ICONS_CLASS } aria-hidden = "true">{ "expand_more" }</span>
+            { content }
+        </button>
+    });
+}
+
+impl MdcWidget for ListGroup {
+    const NAME: &'static str = stringify!(ListGroup);
+
+    fn html(&self) -> &Html {
+        &self.html
+    }
+
+    fn html_mut(&mut self) -> &mut Html {
+        &mut self.html
+    }
+}
+
+impl Deref for ListGroup {
+    type Target = Html;
+
+    fn deref(&self) -> &Self::Target {
+        &self.html
+    }
+}
+
+impl DerefMut for ListGroup {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.html
+    }
+}
+
+impl From<ListGroup> for Html {
+    fn from(group: ListGroup) -> Self {
+        let ListGroup {
+            mut html,
+            keys,
+            collapsed_keys,
+            is_collapsible,
+            has_index_bar,
+        } = group;
+
+        if let Some(root) = html.root_tag_mut() {
+            for key in &collapsed_keys {
+                let header_idx = root
+                    .children()
+                    .iter()
+                    .position(|child| child.attr(ListGroup::KEY_ATTR).as_deref() == Some(key.as_str()));
+                if let Some(list) = header_idx.and_then(|idx| root.get_child_mut(idx + 1)) {
+                    list.set_attr("hidden", "hidden");
+                }
+            }
+            if is_collapsible {
+                let children = root.children_mut().map(|children| children.to_vlist_mut());
+                if let Some(children) = children {
+                    let mut idx = 0;
+                    while idx < children.len() {
+                        let is_expanded = children.get(idx + 1).is_none_or(|list| list.attr("hidden").is_none());
+                        if let Some(Html::VTag(header)) = children.get_mut(idx) {
+                            make_collapsible(header, is_expanded);
+                        }
+                        idx += 2;
+                    }
+                }
+            }
+            if has_index_bar {
+                root.insert_child(0, html! {
+                    <nav key = "index-bar" class = { ListGroup::INDEX_BAR_CLASS } aria-label = "Index">
+                        {
+                            for keys.iter().map(|key| html! {
+                                <a href = "#" data-group-jump = { key.clone() }>{ key }</a>
+                            })
+                        }
+                    </nav>
+                });
+            }
+        }
+        html
+    }
+}
+
+impl ToHtml for ListGroup {
+    fn to_html(&self) -> Html {
+        self.clone().into()
+    }
+
+    fn into_html(self) -> Html {
+        self.into()
+    }
+}
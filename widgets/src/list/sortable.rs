@@ -0,0 +1,260 @@
+use std::rc::Rc;
+
+use const_format::concatcp;
+use gloo::timers::callback::Timeout;
+use yew::html::{
+    onclick, ondragend, ondragover, ondragstart, ondrop, onfocusout, onkeydown, onpointerdown, onpointerup,
+};
+use yew::virtual_dom::Listener;
+use yew::{Callback, DragEvent, Event, FocusEvent, KeyboardEvent, MouseEvent, PointerEvent};
+
+use super::{mdc, List, ListItem};
+use crate::dom::{JsCast, JsValue};
+use crate::utils::emit_custom_event;
+use crate::web_sys::HtmlElement;
+use crate::Element;
+
+pub(super) const REORDER_EVENT: &str = concatcp!(mdc::TYPE_NAME, ":reorder");
+
+const DRAG_FROM_ATTR: &str = "data-drag-from";
+const DROP_TO_ATTR: &str = "data-drop-to";
+const ITEMS_SELECTOR: &str = concatcp!(":scope > .", ListItem::CLASS);
+
+/// Returns the listeners of the sortable list root, which handle the dragging by the item handles
+/// and the keyboard reordering.
+pub(super) fn listeners() -> Vec<Rc<dyn Listener>> {
+    vec![
+        Rc::new(onpointerdown::Wrapper::new(Callback::from(|event: PointerEvent| {
+            if let Some(item) = handle_of(&event).and_then(|handle| item_of(&handle)) {
+                let _ = item.set_attribute("draggable", "true");
+            }
+        }))),
+        Rc::new(onpointerup::Wrapper::new(Callback::from(|event: PointerEvent| {
+            if let Some(item) = handle_of(&event).and_then(|handle| item_of(&handle)) {
+                let _ = item.remove_attribute("draggable");
+            }
+        }))),
+        Rc::new(onclick::Wrapper::new(Callback::from(|event: MouseEvent| {
+            // The handle click is not the item action
+            if handle_of(&event).is_some() {
+                event.stop_propagation();
+            }
+        }))),
+        Rc::new(ondragstart::Wrapper::new(Callback::from(on_drag_start))),
+        Rc::new(ondragover::Wrapper::new(Callback::from(on_drag_over))),
+        Rc::new(ondrop::Wrapper::new(Callback::from(|event: DragEvent| {
+            if let Some(list) = list_of(&event).filter(|list| list.has_attribute(DRAG_FROM_ATTR)) {
+                event.prevent_default();
+                drop_item(&list, false);
+            }
+        }))),
+        Rc::new(ondragend::Wrapper::new(Callback::from(|event: DragEvent| {
+            if let Some(list) = list_of(&event) {
+                finish(&list);
+            }
+        }))),
+        Rc::new(onkeydown::Wrapper::new(Callback::from(on_key_down))),
+        Rc::new(onfocusout::Wrapper::new(Callback::from(|event: FocusEvent| {
+            let is_grabbed = handle_of(&event)
+                .and_then(|handle| item_of(&handle))
+                .is_some_and(|item| item.class_list().contains(ListItem::GRABBED_CLASS));
+            if let (true, Some(list)) = (is_grabbed, list_of(&event)) {
+                finish(&list);
+            }
+        }))),
+    ]
+}
+
+fn on_drag_start(event: DragEvent) {
+    let (Some(list), Some(item)) = (list_of(&event), event_item(&event)) else {
+        return;
+    };
+    let Some(from) = item_index(&list, &item) else {
+        return;
+    };
+    if let Some(transfer) = event.data_transfer() {
+        transfer.set_effect_allowed("move");
+        // Firefox does not start dragging without the data
+        let _ = transfer.set_data("text/plain", "");
+    }
+    let _ = list.set_attribute(DRAG_FROM_ATTR, &from.to_string());
+    let _ = item.class_list().add_1(ListItem::DRAGGING_CLASS);
+}
+
+fn on_drag_over(event: DragEvent) {
+    let (Some(list), Some(item)) = (list_of(&event), event_item(&event)) else {
+        return;
+    };
+    let (Some(from), Some(over)) = (drag_from(&list), item_index(&list, &item)) else {
+        return;
+    };
+    event.prevent_default();
+    if let Some(transfer) = event.data_transfer() {
+        transfer.set_drop_effect("move");
+    }
+
+    let rect = item.get_bounding_client_rect();
+    let is_before = (event.client_y() as f64) < rect.top() + rect.height() / 2.0;
+    let to = match (is_before, over > from) {
+        (true, true) => over - 1,
+        (false, false) if over != from => over + 1,
+        _ => over,
+    };
+    show_placeholder(&list, from, to);
+}
+
+fn on_key_down(event: KeyboardEvent) {
+    let (Some(list), Some(handle)) = (list_of(&event), handle_of(&event)) else {
+        return;
+    };
+    let Some(item) = item_of(&handle) else {
+        return;
+    };
+    let is_grabbed = item.class_list().contains(ListItem::GRABBED_CLASS);
+    let is_handled = match (event.key().as_str(), is_grabbed) {
+        (" " | "Enter", false) => {
+            grab(&list, &item, &handle);
+            true
+        },
+        (" " | "Enter", true) => {
+            drop_item(&list, true);
+            true
+        },
+        ("ArrowUp" | "ArrowDown", true) => {
+            if let (Some(from), Some(to)) = (drag_from(&list), drop_to(&list)) {
+                let last = items(&list).len().saturating_sub(1);
+                let to = if event.key() == "ArrowUp" {
+                    to.saturating_sub(1)
+                } else {
+                    (to + 1).min(last)
+                };
+                show_placeholder(&list, from, to);
+            }
+            true
+        },
+        ("Escape", true) => {
+            finish(&list);
+            true
+        },
+        _ => false,
+    };
+    if is_handled {
+        // The list listeners are run before the MDC ones, so the MDC list does not move the focus
+        event.prevent_default();
+        event.stop_propagation();
+    }
+}
+
+fn grab(list: &Element, item: &Element, handle: &Element) {
+    if let Some(from) = item_index(list, item) {
+        let _ = list.set_attribute(DRAG_FROM_ATTR, &from.to_string());
+        let _ = list.set_attribute(DROP_TO_ATTR, &from.to_string());
+        let _ = item.class_list().add_1(ListItem::GRABBED_CLASS);
+        let _ = handle.set_attribute("aria-pressed", "true");
+    }
+}
+
+/// Emits the reorder event for the dragged item and the placeholder position.
+fn drop_item(list: &Element, refocus: bool) {
+    let moved = drag_from(list).zip(drop_to(list));
+    finish(list);
+
+    let Some((from, to)) = moved.filter(|(from, to)| from != to) else {
+        return;
+    };
+    emit_custom_event(list, REORDER_EVENT, &[
+        ("from", JsValue::from(from as u32)),
+        ("to", JsValue::from(to as u32)),
+    ]);
+
+    if refocus {
+        // The moved item is rendered at the new position after the model is updated
+        let list = list.clone();
+        Timeout::new(0, move || {
+            let handle = items(&list)
+                .get(to)
+                .and_then(|item| item.query_selector(concatcp!(".", ListItem::DRAG_HANDLE_CLASS)).ok())
+                .flatten();
+            if let Some(handle) = handle.and_then(|handle| handle.dyn_into::<HtmlElement>().ok()) {
+                let _ = handle.focus();
+            }
+        })
+        .forget();
+    }
+}
+
+/// Clears the dragging state of the list.
+fn finish(list: &Element) {
+    let _ = list.remove_attribute(DRAG_FROM_ATTR);
+    let _ = list.remove_attribute(DROP_TO_ATTR);
+    for item in items(list) {
+        let _ = item.remove_attribute("draggable");
+        let _ = item.class_list().remove_4(
+            ListItem::DRAGGING_CLASS,
+            ListItem::GRABBED_CLASS,
+            ListItem::DROP_BEFORE_CLASS,
+            ListItem::DROP_AFTER_CLASS,
+        );
+        if let Ok(Some(handle)) = item.query_selector(concatcp!(".", ListItem::DRAG_HANDLE_CLASS)) {
+            let _ = handle.set_attribute("aria-pressed", "false");
+        }
+    }
+}
+
+/// Shows the placeholder at the position, which the dragged item will take after the drop.
+fn show_placeholder(list: &Element, from: usize, to: usize) {
+    let _ = list.set_attribute(DROP_TO_ATTR, &to.to_string());
+    for (idx, item) in items(list).into_iter().enumerate() {
+        let class_list = item.class_list();
+        let _ = class_list.toggle_with_force(ListItem::DROP_BEFORE_CLASS, idx == to && to < from);
+        let _ = class_list.toggle_with_force(ListItem::DROP_AFTER_CLASS, idx == to && to > from);
+    }
+}
+
+fn items(list: &Element) -> Vec<Element> {
+    let Ok(items) = list.query_selector_all(ITEMS_SELECTOR) else {
+        return Vec::new();
+    };
+    (0..items.length())
+        .filter_map(|idx| items.get(idx))
+        .filter_map(|item| item.dyn_into::<Element>().ok())
+        .collect()
+}
+
+fn item_index(list: &Element, item: &Element) -> Option<usize> {
+    items(list).iter().position(|list_item| list_item == item)
+}
+
+fn drag_from(list: &Element) -> Option<usize> {
+    list.get_attribute(DRAG_FROM_ATTR)?.parse().ok()
+}
+
+fn drop_to(list: &Element) -> Option<usize> {
+    list.get_attribute(DROP_TO_ATTR)?.parse().ok()
+}
+
+fn event_target(event: &Event) -> Option<Element> {
+    event.target().and_then(|target| target.dyn_into::<Element>().ok())
+}
+
+fn list_of(event: &Event) -> Option<Element> {
+    event_target(event)?
+        .closest(concatcp!(".", List::SORTABLE_CLASS))
+        .ok()
+        .flatten()
+}
+
+fn event_item(event: &Event) -> Option<Element> {
+    item_of(&event_target(event)?)
+}
+
+fn item_of(element: &Element) -> Option<Element> {
+    element.closest(concatcp!(".", ListItem::CLASS)).ok().flatten()
+}
+
+fn handle_of(event: &Event) -> Option<Element> {
+    event_target(event)?
+        .closest(concatcp!(".", ListItem::DRAG_HANDLE_CLASS))
+        .ok()
+        .flatten()
+}
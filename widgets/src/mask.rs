@@ -0,0 +1,267 @@
+use std::fmt;
+use std::rc::Rc;
+
+pub type MaskFn = dyn Fn(&str) -> String;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Token {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Token {
+    fn accepts(&self, ch: char) -> bool {
+        match self {
+            Self::Digit => ch.is_ascii_digit(),
+            Self::Letter => ch.is_alphabetic(),
+            Self::Alphanumeric => ch.is_alphanumeric(),
+            Self::Literal(_) => false,
+        }
+    }
+}
+
+/// Formats the input value while typing. The mask is a pair of functions: the formatter makes the
+/// masked value from the raw one, the parser extracts the raw value from the masked or partially
+/// typed one.
+#[derive(Clone)]
+pub struct Mask {
+    format: Rc<MaskFn>,
+    parse: Rc<MaskFn>,
+}
+
+impl Mask {
+    pub fn new(format: impl Fn(&str) -> String + 'static, parse: impl Fn(&str) -> String + 'static) -> Self {
+        Self {
+            format: Rc::new(format),
+            parse: Rc::new(parse),
+        }
+    }
+
+    /// Creates the mask from the pattern, like `"+9 (999) 999-99-99"`. The pattern placeholders
+    /// are `9` for a digit, `a` for a letter and `*` for a letter or a digit, the other characters
+    /// are literals. A placeholder character is taken literally after `\`.
+    pub fn pattern(pattern: impl AsRef<str>) -> Self {
+        let mut tokens = Vec::new();
+        let mut chars = pattern.as_ref().chars();
+        while let Some(ch) = chars.next() {
+            tokens.push(match ch {
+                '9' => Token::Digit,
+                'a' => Token::Letter,
+                '*' => Token::Alphanumeric,
+                '\\' => Token::Literal(chars.next().unwrap_or('\\')),
+                ch => Token::Literal(ch),
+            });
+        }
+        let tokens = Rc::new(tokens);
+        let parse_tokens = tokens.clone();
+
+        Self::new(
+            move |raw| format_by_tokens(&tokens, raw),
+            move |masked| parse_by_tokens(&parse_tokens, masked),
+        )
+    }
+
+    /// Bank card number of 16 digits in groups of 4.
+    pub fn card_number() -> Self {
+        Self::pattern("9999 9999 9999 9999")
+    }
+
+    /// Date in the `YYYY-MM-DD` format.
+    pub fn date() -> Self {
+        Self::pattern("9999-99-99")
+    }
+
+    /// IBAN of up to 34 uppercase letters and digits in groups of 4.
+    pub fn iban() -> Self {
+        Self::new(
+            |raw| {
+                let chars: Vec<char> = raw.chars().collect();
+                chars
+                    .chunks(4)
+                    .map(|group| group.iter().collect::<String>())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            },
+            |masked| {
+                masked
+                    .chars()
+                    .filter(char::is_ascii_alphanumeric)
+                    .map(|ch| ch.to_ascii_uppercase())
+                    .take(34)
+                    .collect()
+            },
+        )
+    }
+
+    /// Makes the masked value from the raw one.
+    pub fn format(&self, raw: &str) -> String {
+        (self.format)(raw)
+    }
+
+    /// Extracts the raw value from the masked or partially typed one.
+    pub fn parse(&self, masked: &str) -> String {
+        (self.parse)(masked)
+    }
+
+    /// Returns the character index in the masked value right after the `raw_count` raw characters.
+    pub fn caret_position(&self, masked: &str, raw_count: usize) -> usize {
+        if raw_count == 0 {
+            return 0;
+        }
+        let mut prefix = String::new();
+        for (idx, ch) in masked.chars().enumerate() {
+            prefix.push(ch);
+            if self.parse(&prefix).chars().count() >= raw_count {
+                return idx + 1;
+            }
+        }
+        masked.chars().count()
+    }
+}
+
+fn format_by_tokens(tokens: &[Token], raw: &str) -> String {
+    let mut raw = raw.chars();
+    let mut masked = String::new();
+    let mut literals = String::new();
+
+    for token in tokens {
+        if let Token::Literal(literal) = token {
+            literals.push(*literal);
+            continue;
+        }
+        // The literals are added only before the filled placeholder, so there are no trailing ones
+        match raw.by_ref().find(|ch| token.accepts(*ch)) {
+            Some(ch) => {
+                masked.push_str(&literals);
+                literals.clear();
+                masked.push(ch);
+            },
+            None => break,
+        }
+    }
+    masked
+}
+
+fn parse_by_tokens(tokens: &[Token], masked: &str) -> String {
+    let mut raw = String::new();
+    let mut idx = 0;
+
+    for ch in masked.chars() {
+        loop {
+            match tokens.get(idx) {
+                None => return raw,
+                Some(Token::Literal(literal)) => {
+                    // The missing literals are skipped, so the raw value can be pasted as is
+                    idx += 1;
+                    if *literal == ch {
+                        break;
+                    }
+                },
+                Some(token) => {
+                    if token.accepts(ch) {
+                        raw.push(ch);
+                        idx += 1;
+                    }
+                    break;
+                },
+            }
+        }
+    }
+    raw
+}
+
+impl From<&str> for Mask {
+    fn from(pattern: &str) -> Self {
+        Self::pattern(pattern)
+    }
+}
+
+impl fmt::Debug for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Mask)).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHONE: &str = "+9 (999) 999-99-99";
+
+    #[test]
+    fn pattern_formats_complete_value() {
+        let mask = Mask::pattern(PHONE);
+        assert_eq!(mask.format("79161234567"), "+7 (916) 123-45-67");
+        assert_eq!(Mask::date().format("20240131"), "2024-01-31");
+        assert_eq!(Mask::card_number().format("1234567890123456"), "1234 5678 9012 3456");
+    }
+
+    #[test]
+    fn pattern_formats_partial_value_without_trailing_literals() {
+        let mask = Mask::pattern(PHONE);
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("7"), "+7");
+        assert_eq!(mask.format("791"), "+7 (91");
+        assert_eq!(mask.format("7916"), "+7 (916");
+    }
+
+    #[test]
+    fn pattern_skips_rejected_characters() {
+        assert_eq!(Mask::pattern(PHONE).format("7a9x"), "+7 (9");
+        assert_eq!(Mask::pattern("aa-99").format("a1b2"), "ab-2");
+        assert_eq!(Mask::pattern("**-**").format("a1-b2"), "a1-b2");
+    }
+
+    #[test]
+    fn pattern_takes_escaped_placeholder_literally() {
+        let mask = Mask::pattern(r"\9-99");
+        assert_eq!(mask.format("12"), "9-12");
+        assert_eq!(mask.parse("9-12"), "12");
+    }
+
+    #[test]
+    fn pattern_parses_masked_and_pasted_values() {
+        let mask = Mask::pattern(PHONE);
+        assert_eq!(mask.parse("+7 (916) 123-45-67"), "79161234567");
+        assert_eq!(mask.parse("+7 (91"), "791");
+        assert_eq!(mask.parse("79161234567"), "79161234567");
+        assert_eq!(mask.parse(""), "");
+    }
+
+    #[test]
+    fn pattern_parses_only_as_many_characters_as_placeholders() {
+        assert_eq!(
+            Mask::card_number().parse("1234 5678 9012 3456 7890"),
+            "1234567890123456"
+        );
+    }
+
+    #[test]
+    fn iban_groups_uppercase_characters() {
+        let mask = Mask::iban();
+        assert_eq!(mask.format("DE89370400440532013000"), "DE89 3704 0044 0532 0130 00");
+        assert_eq!(mask.parse("de89 3704-0044"), "DE8937040044");
+        assert_eq!(mask.parse(&"A".repeat(40)).len(), 34);
+    }
+
+    #[test]
+    fn caret_position_follows_raw_characters() {
+        let mask = Mask::pattern(PHONE);
+        let masked = "+7 (916) 12";
+        assert_eq!(mask.caret_position(masked, 0), 0);
+        assert_eq!(mask.caret_position(masked, 1), 2);
+        assert_eq!(mask.caret_position(masked, 2), 5);
+        assert_eq!(mask.caret_position(masked, 4), 7);
+        assert_eq!(mask.caret_position(masked, 5), 10);
+        assert_eq!(mask.caret_position(masked, 100), masked.chars().count());
+    }
+
+    #[test]
+    fn custom_mask_uses_given_functions() {
+        let mask = Mask::new(|raw| raw.to_uppercase(), |masked| masked.to_lowercase());
+        assert_eq!(mask.format("abc"), "ABC");
+        assert_eq!(mask.parse("ABC"), "abc");
+    }
+}
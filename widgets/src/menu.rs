@@ -1,21 +1,94 @@
+use std::cell::RefCell;
 use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
 
+use const_format::concatcp;
+use gloo::timers::callback::Timeout;
+use yew::html::{onkeydown, onmouseover};
 use yew::virtual_dom::AttrValue;
-use yew::{html, Html, ToHtml};
+use yew::{classes, html, Callback, Event, Html, KeyboardEvent, MouseEvent, ToHtml};
 
 use crate::dom::existing::JsObjectAccess;
-use crate::dom::{self};
-use crate::utils::{ManageChildren, VTagExt};
-use crate::{Element, List, MdcWidget, AUTO_INIT_ATTR};
+use crate::dom::{self, JsCast};
+use crate::utils::{mdc_object, ManageChildren, VTagExt};
+use crate::web_sys::Node;
+use crate::{
+    console, menu_surface, Corner, CustomEvent, Element, EventListenerOptions, GlooEventListener, List, ListItem,
+    MdcWidget, MenuSurface, AUTO_INIT_ATTR, MATERIAL_ICONS_CLASS,
+};
 
 pub mod mdc {
+    use wasm_bindgen::prelude::*;
+
+    use crate::Element;
+
     pub const TYPE_NAME: &str = "MDCMenu";
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_name = MDCMenu)]
+        pub type Menu;
+
+        /// Returns whether the menu is open.
+        #[wasm_bindgen(method, getter)]
+        pub fn open(this: &Menu) -> bool;
+
+        /// Opens or closes the menu.
+        #[wasm_bindgen(method, setter)]
+        pub fn set_open(this: &Menu, open: bool);
+
+        /// Returns the list item elements of the menu.
+        #[wasm_bindgen(method, getter)]
+        pub fn items(this: &Menu) -> Vec<Element>;
+
+        /// Selects the item at the index, which must be in a selection group. The other item of the
+        /// group is unselected.
+        #[wasm_bindgen(method, catch, js_name = setSelectedIndex)]
+        pub fn set_selected_index(this: &Menu, index: u32) -> Result<(), JsValue>;
+
+        /// Sets whether the menu is positioned relative to the viewport.
+        #[wasm_bindgen(method, js_name = setFixedPosition)]
+        pub fn set_fixed_position(this: &Menu, is_fixed: bool);
+
+        /// Sets the absolute x/y position of the menu, the menu becomes hoisted.
+        #[wasm_bindgen(method, js_name = setAbsolutePosition)]
+        pub fn set_absolute_position(this: &Menu, x: f64, y: f64);
+
+        /// Sets the element, which the menu is positioned relative to.
+        #[wasm_bindgen(method, js_name = setAnchorElement)]
+        pub fn set_anchor_element(this: &Menu, element: &Element);
+
+        /// Sets the corner of the anchor element, which the menu is positioned relative to.
+        #[wasm_bindgen(method, js_name = setAnchorCorner)]
+        pub fn set_anchor_corner(this: &Menu, corner: u32);
+
+        /// Sets whether the menu is attached to the body element.
+        #[wasm_bindgen(method, js_name = setIsHoisted)]
+        pub fn set_is_hoisted(this: &Menu, is_hoisted: bool);
+
+        /// Sets which element is focused when the menu is opened: `0` - none, `1` - the list, `2` -
+        /// the first item, `3` - the last item.
+        #[wasm_bindgen(method, js_name = setDefaultFocusState)]
+        pub fn set_default_focus_state(this: &Menu, focus_state: u32);
+    }
+}
+
+/// The menu item, which is selected by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuSelection {
+    /// The index of the item among all the menu items, including the selection group items.
+    pub index: usize,
+    /// The ID of the item element.
+    pub id: Option<String>,
+    /// The value of the item, set by [`ListItem::value`].
+    pub value: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct Menu {
     html: Html,
     list: List,
+    submenus: Vec<Menu>,
 }
 
 impl Default for Menu {
@@ -25,9 +98,26 @@ impl Default for Menu {
 }
 
 impl Menu {
+    pub const CLASS: &'static str = "mdc-menu";
     pub const VAR_NAME: &'static str = "menu";
     pub const ANCHOR_CLASS: &'static str = "mdc-menu-surface--anchor";
 
+    /// Groups the items, only one of which can be selected at a time.
+    pub const SELECTION_GROUP_CLASS: &'static str = "mdc-menu__selection-group";
+
+    /// The icon, which is shown for the selected item of the selection group.
+    pub const SELECTION_GROUP_ICON_CLASS: &'static str = "mdc-menu__selection-group-icon";
+
+    /// Indicates the selected item of the selection group.
+    pub const SELECTED_ITEM_CLASS: &'static str = "mdc-menu-item--selected";
+
+    /// Holds the ID of the submenu, which is opened by the item.
+    pub const SUBMENU_ATTR: &'static str = "data-submenu-id";
+
+    /// Holds the fixed position state of the menu opened at the position, which is restored on
+    /// close.
+    const FIXED_BEFORE_OPEN_ATTR: &'static str = "data-fixed-before-open";
+
     pub fn from_list(list: List) -> Self {
         let list = list.markup_only().attr("role", "menu");
         let mut menu = Self {
@@ -35,6 +125,7 @@ impl Menu {
                 <div class = "mdc-menu mdc-menu-surface"></div>
             },
             list,
+            submenus: Vec::new(),
         };
         menu.root_tag_mut().set_attr(AUTO_INIT_ATTR, mdc::TYPE_NAME);
         menu
@@ -49,6 +140,70 @@ impl Menu {
         menu.set("open", true);
     }
 
+    /// Opens the existing menu at the viewport position, like the `clientX`/`clientY` of a mouse
+    /// event. The menu is flipped to stay inside the viewport, and it is closed on scroll or on the
+    /// Escape key. The menu must not be inside an anchor element. The fixed position, which the
+    /// menu takes while it is open, is restored on close.
+    pub fn open_at_existing(id: impl AsRef<str>, x: f64, y: f64) {
+        let id = id.as_ref();
+        if let Some(menu) = Self::existing(id) {
+            let element = dom::existing::get_element_by_id::<Element>(id);
+            // The menu can be reopened at another position before it is closed
+            if !element.has_attribute(Self::FIXED_BEFORE_OPEN_ATTR) {
+                let is_fixed = element.class_list().contains(MenuSurface::FIXED_CLASS);
+                let _ = element.set_attribute(Self::FIXED_BEFORE_OPEN_ATTR, &is_fixed.to_string());
+                close_on_scroll_or_escape(element);
+            }
+            menu.set_fixed_position(true);
+            menu.set_absolute_position(x, y);
+            menu.set_open(true);
+        }
+    }
+
+    /// Returns the `contextmenu` event callback, which opens the existing menu at the pointer
+    /// instead of the browser context menu.
+    pub fn open_on_context_menu(id: impl Into<AttrValue>) -> Callback<MouseEvent> {
+        let id = id.into();
+        Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+            Self::open_at_existing(&id, event.client_x() as f64, event.client_y() as f64);
+        })
+    }
+
+    pub fn close_existing(id: impl AsRef<str>) {
+        if let Some(menu) = Self::existing(id.as_ref()) {
+            menu.set_open(false);
+        }
+    }
+
+    pub fn is_open_existing(id: impl AsRef<str>) -> bool {
+        Self::existing(id.as_ref()).is_some_and(|menu| menu.open())
+    }
+
+    /// Selects the item of the selection group in the existing menu. The index counts all the menu
+    /// items.
+    pub fn set_selected_index(id: impl AsRef<str>, index: usize) {
+        if let Some(menu) = Self::existing(id.as_ref()) {
+            if menu.set_selected_index(index as u32).is_err() {
+                console::error!(format!(
+                    "The item {} of the {} '{}' is not in a selection group",
+                    index,
+                    Self::NAME,
+                    id.as_ref()
+                ));
+            }
+        }
+    }
+
+    fn existing(id: &str) -> Option<mdc::Menu> {
+        let menu = dom::existing::get_element_by_id::<Element>(id);
+        let menu = mdc_object(&menu, mdc::TYPE_NAME).map(JsCast::unchecked_into);
+        if menu.is_none() {
+            console::error!(format!("The {} with ID '{}' is not initialized", Self::NAME, id));
+        }
+        menu
+    }
+
     pub fn open(self) -> Self {
         let statement = format!("{}.open = true;", Self::VAR_NAME);
         self.add_script_statement(statement)
@@ -77,7 +232,7 @@ impl Menu {
                 statement = statement,
             );
 
-            let Self { html, list } = self;
+            let Self { html, list, submenus } = self;
             self = Self {
                 html: html! {
                     <>
@@ -86,6 +241,7 @@ impl Menu {
                     </>
                 },
                 list,
+                submenus,
             };
         }
         self
@@ -126,6 +282,253 @@ impl Menu {
         self.list = self.list.divider_inset_padding();
         self
     }
+
+    /// Adds the group of items, only one of which is selected at a time. The selected item is
+    /// marked with the checkmark icon.
+    pub fn selection_group(mut self, items: impl IntoIterator<Item = ListItem>, selected: Option<usize>) -> Self {
+        let mut group = html! { <ul class = { Self::SELECTION_GROUP_CLASS }></ul> };
+        for (idx, mut item) in items.into_iter().enumerate() {
+            let root = item.root_tag_mut();
+            let icon_idx = root
+                .find_child_contains_class_idx(ListItem::RIPPLE_CLASS)
+                .map(|idx| idx + 1)
+                .unwrap_or(0);
+            root.insert_child(icon_idx, html! {
+                <span class = { classes!(ListItem::FIRST_TILE_CLASS, Self::SELECTION_GROUP_ICON_CLASS, MATERIAL_ICONS_CLASS) }
+                        aria-hidden = "true">
+                    { "check" }
+                </span>
+            });
+            if selected == Some(idx) {
+                root.add_class_if_needed(Self::SELECTED_ITEM_CLASS);
+                root.set_attr("aria-checked", "true");
+            }
+            group.add_child(Html::from(item.attr("role", "menuitem")));
+        }
+        self.list = self.list.item(html! { <li>{ group }</li> });
+        self
+    }
+
+    /// Adds the item, which opens the nested menu on hover or on the ArrowRight key. The submenu is
+    /// shown to the side of the item and is closed on the ArrowLeft or Escape keys, or when the
+    /// parent menu is closed. The submenu must have ID.
+    pub fn submenu(mut self, mut item: ListItem, submenu: Menu) -> Self {
+        let submenu_id = submenu.root_id();
+        let root = item.root_tag_mut();
+        root.add_child(html! {
+            <span class = { classes!(ListItem::LAST_TILE_CLASS, MATERIAL_ICONS_CLASS) } aria-hidden = "true">
+                { "chevron_right" }
+            </span>
+        });
+        root.set_attr(Self::SUBMENU_ATTR, submenu_id.clone());
+        root.set_attr("aria-haspopup", "menu");
+        root.set_attr("aria-expanded", "false");
+        root.set_attr("aria-controls", submenu_id);
+        self.list = self.list.item(item.attr("role", "menuitem"));
+        self.submenus.push(submenu);
+        self
+    }
+
+    /// Emits when an item is selected by the user.
+    pub fn on_selected(self, callback: impl Into<Callback<MenuSelection>>) -> Self {
+        let callback = callback.into();
+        self.on_event(concatcp!(mdc::TYPE_NAME, ":selected"), move |event: CustomEvent| {
+            let detail = event.detail();
+            let item = detail.get("item").dyn_into::<Element>().ok();
+            callback.emit(MenuSelection {
+                index: detail.get("index").as_f64().unwrap_or_default() as usize,
+                id: item
+                    .as_ref()
+                    .and_then(|item| item.get_attribute("id"))
+                    .filter(|id| !id.is_empty()),
+                value: item.and_then(|item| item.get_attribute(ListItem::VALUE_ATTR)),
+            });
+        })
+    }
+
+    /// Opens the submenus on hover or on the ArrowRight key, and closes them with the menu.
+    fn with_submenu_listeners(self) -> Self {
+        let on_hover = Callback::from(|event: MouseEvent| {
+            if let (Some(menu), Some(item)) = (event_menu(&event), event_item(&event)) {
+                close_submenus(&menu, Some(&item));
+                open_submenu(&item, false);
+            }
+        });
+        let on_keydown = Callback::from(|event: KeyboardEvent| {
+            let item = event_item(&event).filter(|item| item.has_attribute(Self::SUBMENU_ATTR));
+            if let (Some(item), "ArrowRight") = (item, event.key().as_str()) {
+                event.prevent_default();
+                open_submenu(&item, true);
+            }
+        });
+        self.listener(Rc::new(onmouseover::Wrapper::new(on_hover)))
+            .listener(Rc::new(onkeydown::Wrapper::new(on_keydown)))
+            .on_event(
+                concatcp!(menu_surface::mdc::TYPE_NAME, ":closed"),
+                |event: CustomEvent| {
+                    if let Some(menu) = event_menu(&event) {
+                        close_submenus(&menu, None);
+                    }
+                },
+            )
+    }
+
+    /// Closes the submenu on the ArrowLeft key, and the parent menus when an item is selected.
+    fn with_parent_listeners(self) -> Self {
+        let on_keydown = Callback::from(|event: KeyboardEvent| {
+            if let (Some(submenu), "ArrowLeft") = (event_menu(&event), event.key().as_str()) {
+                event.prevent_default();
+                close_menu(&submenu);
+            }
+        });
+        self.listener(Rc::new(onkeydown::Wrapper::new(on_keydown)))
+            .on_event(
+                concatcp!(menu_surface::mdc::TYPE_NAME, ":closed"),
+                |event: CustomEvent| {
+                    if let Some(item) = event_menu(&event).and_then(|submenu| parent_item_of(&submenu)) {
+                        let _ = item.set_attribute("aria-expanded", "false");
+                    }
+                },
+            )
+            .on_event(concatcp!(mdc::TYPE_NAME, ":selected"), |event: CustomEvent| {
+                let mut parent = event_menu(&event).and_then(|submenu| parent_menu_of_submenu(&submenu));
+                while let Some(menu) = parent {
+                    close_menu(&menu);
+                    parent = parent_menu_of_submenu(&menu);
+                }
+            })
+    }
+}
+
+/// Closes the menu opened at the position on scroll or on the Escape key, and restores its fixed
+/// position state on close.
+fn close_on_scroll_or_escape(menu: Element) {
+    let document = dom::existing::document();
+    let listeners = Rc::new(RefCell::new(Vec::new()));
+
+    let scroll = {
+        let menu = menu.clone();
+        // Scroll events don't bubble, so they are caught in the capture phase
+        GlooEventListener::new_with_options(
+            &document,
+            "scroll",
+            EventListenerOptions::run_in_capture_phase(),
+            move |event| {
+                let is_menu_scroll = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Node>().ok())
+                    .is_some_and(|target| menu.contains(Some(&target)));
+                if !is_menu_scroll {
+                    close_menu(&menu);
+                }
+            },
+        )
+    };
+    let escape = {
+        let menu = menu.clone();
+        GlooEventListener::new(&document, "keydown", move |event| {
+            if event
+                .dyn_ref::<KeyboardEvent>()
+                .is_some_and(|event| event.key() == "Escape")
+            {
+                close_menu(&menu);
+            }
+        })
+    };
+    let closed = {
+        let listeners = listeners.clone();
+        let element = menu.clone();
+        GlooEventListener::new(&menu, concatcp!(menu_surface::mdc::TYPE_NAME, ":closed"), move |_| {
+            let is_fixed = element.get_attribute(Menu::FIXED_BEFORE_OPEN_ATTR).as_deref() == Some("true");
+            let _ = element.remove_attribute(Menu::FIXED_BEFORE_OPEN_ATTR);
+            if let Some(menu) = mdc_object(&element, mdc::TYPE_NAME) {
+                menu.unchecked_into::<mdc::Menu>().set_fixed_position(is_fixed);
+            }
+            // The listeners can't be dropped while this one is running
+            let listeners = listeners.take();
+            Timeout::new(0, move || drop(listeners)).forget();
+        })
+    };
+    listeners.replace(vec![scroll, escape, closed]);
+}
+
+/// Opens the submenu of the parent menu item. The submenu is anchored to the end of the item, and
+/// its fixed position keeps it from being clipped by the `overflow` of the menu ancestors.
+fn open_submenu(item: &Element, focus_first_item: bool) {
+    let Some(submenu) = submenu_of(item) else {
+        return;
+    };
+    let Some(menu) = mdc_object(&submenu, mdc::TYPE_NAME).map(JsCast::unchecked_into::<mdc::Menu>) else {
+        return;
+    };
+    if !menu.open() {
+        menu.set_anchor_element(item);
+        menu.set_anchor_corner(Corner::TopEnd.value());
+        menu.set_fixed_position(true);
+        menu.set_default_focus_state(if focus_first_item { 2 } else { 0 });
+        menu.set_open(true);
+        let _ = item.set_attribute("aria-expanded", "true");
+    }
+}
+
+/// Closes the open submenus of the menu, except the submenu of the item.
+fn close_submenus(menu: &Element, except: Option<&Element>) {
+    let Ok(items) = menu.query_selector_all(concatcp!("[", Menu::SUBMENU_ATTR, "]")) else {
+        return;
+    };
+    for idx in 0..items.length() {
+        let Some(item) = items.get(idx).and_then(|item| item.dyn_into::<Element>().ok()) else {
+            continue;
+        };
+        if except != Some(&item) {
+            if let Some(submenu) = submenu_of(&item) {
+                close_menu(&submenu);
+            }
+        }
+    }
+}
+
+fn submenu_of(item: &Element) -> Option<Element> {
+    let id = item.get_attribute(Menu::SUBMENU_ATTR)?;
+    dom::existing::document().get_element_by_id(&id)
+}
+
+/// Returns the item of the parent menu, which opens the submenu.
+fn parent_item_of(submenu: &Element) -> Option<Element> {
+    dom::existing::document()
+        .query_selector(&format!("[{}='{}']", Menu::SUBMENU_ATTR, submenu.id()))
+        .ok()
+        .flatten()
+}
+
+fn parent_menu_of_submenu(submenu: &Element) -> Option<Element> {
+    parent_item_of(submenu).and_then(|item| parent_menu_of(&item))
+}
+
+fn parent_menu_of(element: &Element) -> Option<Element> {
+    element.closest(concatcp!(".", Menu::CLASS)).ok().flatten()
+}
+
+fn event_item(event: &Event) -> Option<Element> {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|target| target.closest(concatcp!(".", ListItem::CLASS)).ok().flatten())
+}
+
+/// Returns the menu element, which contains the event target. The submenus are not nested into
+/// the parent menu elements, so this is the menu, which the event listener is attached to.
+fn event_menu(event: &Event) -> Option<Element> {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|target| parent_menu_of(&target))
+}
+
+fn close_menu(menu: &Element) {
+    if let Some(menu) = mdc_object(menu, mdc::TYPE_NAME) {
+        menu.unchecked_into::<mdc::Menu>().set_open(false);
+    }
 }
 
 impl MdcWidget for Menu {
@@ -155,12 +558,29 @@ impl DerefMut for Menu {
 }
 
 impl From<Menu> for Html {
-    fn from(menu: Menu) -> Self {
-        let Menu { mut html, list } = menu;
+    fn from(mut menu: Menu) -> Self {
+        if !menu.submenus.is_empty() {
+            menu = menu.with_submenu_listeners();
+        }
+        let Menu {
+            mut html,
+            list,
+            submenus,
+        } = menu;
         if let Html::VTag(tag) = &mut html {
             tag.insert_child(0, Html::from(list));
         }
-        html
+        if submenus.is_empty() {
+            return html;
+        }
+
+        // The submenus are rendered next to the parent menu, so that they are not counted as its items
+        html! {
+            <>
+                { html }
+                { for submenus.into_iter().map(|submenu| Html::from(submenu.with_parent_listeners())) }
+            </>
+        }
     }
 }
 
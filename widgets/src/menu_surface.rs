@@ -0,0 +1,374 @@
+use std::ops::{Deref, DerefMut};
+
+use const_format::concatcp;
+use yew::{html, Callback, Html, ToHtml};
+
+use crate::dom::{self, JsCast};
+use crate::utils::{mdc_object, VTagExt};
+use crate::{console, js_sys, CustomEvent, Element, MdcWidget, AUTO_INIT_ATTR};
+
+pub mod mdc {
+    use wasm_bindgen::prelude::*;
+
+    use crate::Element;
+
+    pub const TYPE_NAME: &str = "MDCMenuSurface";
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_name = MDCMenuSurface, js_namespace = ["mdc", "menuSurface"])]
+        pub type MenuSurface;
+
+        /// Returns whether or not the menu surface is open.
+        #[wasm_bindgen(method, js_name = isOpen)]
+        pub fn is_open(this: &MenuSurface) -> bool;
+
+        /// Opens the menu surface.
+        #[wasm_bindgen(method)]
+        pub fn open(this: &MenuSurface);
+
+        /// Closes the menu surface, optionally with skipping the restoration of the focus.
+        #[wasm_bindgen(method)]
+        pub fn close(this: &MenuSurface, skip_restore_focus: bool);
+
+        /// Sets the corner of the anchor element that the menu surface is positioned against.
+        #[wasm_bindgen(method, js_name = setAnchorCorner)]
+        pub fn set_anchor_corner(this: &MenuSurface, corner: u32);
+
+        /// Sets the distance from the anchor element, the margin is `{top, right, bottom, left}`.
+        #[wasm_bindgen(method, js_name = setAnchorMargin)]
+        pub fn set_anchor_margin(this: &MenuSurface, margin: &JsValue);
+
+        /// Sets the element that the menu surface is positioned against.
+        #[wasm_bindgen(method, js_name = setMenuSurfaceAnchorElement)]
+        pub fn set_anchor_element(this: &MenuSurface, element: &Element);
+
+        /// Sets whether the menu surface is positioned relative to the viewport.
+        #[wasm_bindgen(method, js_name = setFixedPosition)]
+        pub fn set_fixed_position(this: &MenuSurface, is_fixed: bool);
+
+        /// Sets the absolute x/y position of the menu surface, the surface becomes hoisted.
+        #[wasm_bindgen(method, js_name = setAbsolutePosition)]
+        pub fn set_absolute_position(this: &MenuSurface, x: f64, y: f64);
+
+        /// Sets whether the menu surface is positioned relative to the page rather than its parent.
+        #[wasm_bindgen(method, js_name = setIsHoisted)]
+        pub fn set_is_hoisted(this: &MenuSurface, is_hoisted: bool);
+
+        /// Sets whether the menu surface opens and closes without the animation.
+        #[wasm_bindgen(method, setter = quickOpen)]
+        pub fn set_quick_open(this: &MenuSurface, quick_open: bool);
+    }
+}
+
+/// The corner of the anchor element, which the menu surface is positioned against.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Corner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    #[default]
+    TopStart,
+    TopEnd,
+    BottomStart,
+    BottomEnd,
+}
+
+impl Corner {
+    /// The value of the MDC `Corner` enum.
+    pub fn value(&self) -> u32 {
+        match self {
+            Self::TopLeft => 0,
+            Self::BottomLeft => 1,
+            Self::TopRight => 4,
+            Self::BottomRight => 5,
+            Self::TopStart => 8,
+            Self::BottomStart => 9,
+            Self::TopEnd => 12,
+            Self::BottomEnd => 13,
+        }
+    }
+}
+
+/// The distance in pixels between the menu surface and the anchor corner.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct AnchorMargin {
+    pub top: f64,
+    pub right: f64,
+    pub bottom: f64,
+    pub left: f64,
+}
+
+impl AnchorMargin {
+    fn to_attr(self) -> String {
+        format!("{} {} {} {}", self.top, self.right, self.bottom, self.left)
+    }
+
+    fn from_attr(attr: &str) -> Option<Self> {
+        let mut values = attr.split_whitespace().map(|value| value.parse::<f64>().ok());
+        Some(Self {
+            top: values.next()??,
+            right: values.next()??,
+            bottom: values.next()??,
+            left: values.next()??,
+        })
+    }
+}
+
+/// The popover surface with arbitrary content, which is positioned against the anchor element or
+/// at the absolute position.
+///
+/// The positioning options are applied by [`MenuSurface::open_existing`] and
+/// [`MenuSurface::open_at_existing`], so the surface must have an ID.
+#[derive(Debug, Clone)]
+pub struct MenuSurface {
+    html: Html,
+    anchor: Option<Html>,
+}
+
+impl Default for MenuSurface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MenuSurface {
+    pub const CLASS: &'static str = "mdc-menu-surface";
+
+    /// Positions the menu surface against the element, which must be the surface parent.
+    pub const ANCHOR_CLASS: &'static str = "mdc-menu-surface--anchor";
+
+    /// Indicates the opened surface.
+    pub const OPEN_CLASS: &'static str = "mdc-menu-surface--open";
+
+    /// Positions the surface relative to the viewport.
+    pub const FIXED_CLASS: &'static str = "mdc-menu-surface--fixed";
+
+    /// Makes the surface of the anchor width.
+    pub const FULLWIDTH_CLASS: &'static str = "mdc-menu-surface--fullwidth";
+
+    const ANCHOR_ID_ATTR: &'static str = "data-anchor-id";
+    const ANCHOR_CORNER_ATTR: &'static str = "data-anchor-corner";
+    const ANCHOR_MARGIN_ATTR: &'static str = "data-anchor-margin";
+    const HOISTED_ATTR: &'static str = "data-hoisted";
+    const QUICK_OPEN_ATTR: &'static str = "data-quick-open";
+    const POSITION_ATTR: &'static str = "data-position";
+
+    pub fn simple() -> Self {
+        Self {
+            html: html! { <div class = { Self::CLASS }></div> },
+            anchor: None,
+        }
+    }
+
+    pub fn new() -> Self {
+        let mut menu_surface = Self::simple();
+        menu_surface.root_tag_mut().set_attr(AUTO_INIT_ATTR, mdc::TYPE_NAME);
+        menu_surface
+    }
+
+    /// Renders the anchor element together with the surface inside the anchor container.
+    pub fn anchor(mut self, anchor: impl Into<Html>) -> Self {
+        self.anchor = Some(anchor.into());
+        self
+    }
+
+    /// Positions the surface against the existing element with the ID, which is not the surface
+    /// parent.
+    pub fn anchor_id(self, id: impl Into<String>) -> Self {
+        self.attr(Self::ANCHOR_ID_ATTR, id)
+    }
+
+    pub fn anchor_corner(self, corner: Corner) -> Self {
+        self.attr(Self::ANCHOR_CORNER_ATTR, corner.value().to_string())
+    }
+
+    pub fn anchor_margin(self, margin: AnchorMargin) -> Self {
+        self.attr(Self::ANCHOR_MARGIN_ATTR, margin.to_attr())
+    }
+
+    /// Opens the surface at the absolute `x`/`y` position instead of the anchor corner.
+    pub fn position(self, x: f64, y: f64) -> Self {
+        self.attr(Self::POSITION_ATTR, format!("{} {}", x, y))
+    }
+
+    /// Positions the surface relative to the viewport, for example, when the anchor is in a fixed
+    /// element like a top app bar.
+    pub fn fixed(self) -> Self {
+        self.class(Self::FIXED_CLASS)
+    }
+
+    pub fn fullwidth(self) -> Self {
+        self.class(Self::FULLWIDTH_CLASS)
+    }
+
+    /// Positions the surface relative to the page rather than its parent. Use it when the surface
+    /// is rendered into the body, outside of any positioned element.
+    pub fn hoisted(self) -> Self {
+        self.attr(Self::HOISTED_ATTR, "true")
+    }
+
+    /// Opens and closes the surface without the animation.
+    pub fn quick_open(self) -> Self {
+        self.attr(Self::QUICK_OPEN_ATTR, "true")
+    }
+
+    pub fn content(self, content: impl Into<Html>) -> Self {
+        self.child(content)
+    }
+
+    /// Opens the surface at the anchor or at the configured position.
+    pub fn open_existing(id: impl AsRef<str>) {
+        if let Some((element, surface)) = Self::existing(id.as_ref()) {
+            Self::configure(&element, &surface);
+            let position = element.get_attribute(Self::POSITION_ATTR).and_then(|position| {
+                let mut coords = position.split_whitespace().map(|coord| coord.parse::<f64>().ok());
+                Some((coords.next()??, coords.next()??))
+            });
+            if let Some((x, y)) = position {
+                surface.set_absolute_position(x, y);
+            }
+            surface.open();
+        }
+    }
+
+    /// Opens the surface at the absolute `x`/`y` position. For the fixed surface the position is
+    /// relative to the viewport, like the `clientX`/`clientY` of a mouse event.
+    pub fn open_at_existing(id: impl AsRef<str>, x: f64, y: f64) {
+        if let Some((element, surface)) = Self::existing(id.as_ref()) {
+            Self::configure(&element, &surface);
+            surface.set_absolute_position(x, y);
+            surface.open();
+        }
+    }
+
+    pub fn close_existing(id: impl AsRef<str>) {
+        if let Some((_, surface)) = Self::existing(id.as_ref()) {
+            surface.close(false);
+        }
+    }
+
+    pub fn is_open_existing(id: impl AsRef<str>) -> bool {
+        Self::existing(id.as_ref()).is_some_and(|(_, surface)| surface.is_open())
+    }
+
+    /// Indicates when the surface begins its opening animation.
+    /// event.detail: `{}`
+    pub fn on_opening(self, callback: impl Into<Callback<CustomEvent>>) -> Self {
+        self.on_event(concatcp!(mdc::TYPE_NAME, ":opening"), callback)
+    }
+
+    /// Indicates when the surface finishes its opening animation.
+    /// event.detail: `{}`
+    pub fn on_opened(self, callback: impl Into<Callback<CustomEvent>>) -> Self {
+        self.on_event(concatcp!(mdc::TYPE_NAME, ":opened"), callback)
+    }
+
+    /// Indicates when the surface begins its closing animation.
+    /// event.detail: `{}`
+    pub fn on_closing(self, callback: impl Into<Callback<CustomEvent>>) -> Self {
+        self.on_event(concatcp!(mdc::TYPE_NAME, ":closing"), callback)
+    }
+
+    /// Indicates when the surface finishes its closing animation.
+    /// event.detail: `{}`
+    pub fn on_closed(self, callback: impl Into<Callback<CustomEvent>>) -> Self {
+        self.on_event(concatcp!(mdc::TYPE_NAME, ":closed"), callback)
+    }
+
+    fn existing(id: &str) -> Option<(Element, mdc::MenuSurface)> {
+        let element = dom::existing::get_element_by_id::<Element>(id);
+        match mdc_object(&element, mdc::TYPE_NAME) {
+            Some(surface) => Some((element, surface.unchecked_into())),
+            None => {
+                console::error!(format!("The {} with ID '{}' is not initialized", Self::NAME, id));
+                None
+            },
+        }
+    }
+
+    fn configure(element: &Element, surface: &mdc::MenuSurface) {
+        if let Some(anchor) = element
+            .get_attribute(Self::ANCHOR_ID_ATTR)
+            .and_then(|id| dom::existing::document().get_element_by_id(&id))
+        {
+            surface.set_anchor_element(&anchor);
+        }
+        if let Some(corner) = element
+            .get_attribute(Self::ANCHOR_CORNER_ATTR)
+            .and_then(|corner| corner.parse().ok())
+        {
+            surface.set_anchor_corner(corner);
+        }
+        if let Some(margin) = element
+            .get_attribute(Self::ANCHOR_MARGIN_ATTR)
+            .and_then(|margin| AnchorMargin::from_attr(&margin))
+        {
+            let object = js_sys::Object::new();
+            for (side, value) in [
+                ("top", margin.top),
+                ("right", margin.right),
+                ("bottom", margin.bottom),
+                ("left", margin.left),
+            ] {
+                let _ = js_sys::Reflect::set(&object, &side.into(), &value.into());
+            }
+            surface.set_anchor_margin(&object);
+        }
+        surface.set_fixed_position(element.class_list().contains(Self::FIXED_CLASS));
+        surface.set_is_hoisted(element.has_attribute(Self::HOISTED_ATTR));
+        surface.set_quick_open(element.has_attribute(Self::QUICK_OPEN_ATTR));
+    }
+}
+
+impl MdcWidget for MenuSurface {
+    const NAME: &'static str = stringify!(MenuSurface);
+
+    fn html(&self) -> &Html {
+        &self.html
+    }
+
+    fn html_mut(&mut self) -> &mut Html {
+        &mut self.html
+    }
+}
+
+impl Deref for MenuSurface {
+    type Target = Html;
+
+    fn deref(&self) -> &Self::Target {
+        &self.html
+    }
+}
+
+impl DerefMut for MenuSurface {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.html
+    }
+}
+
+impl From<MenuSurface> for Html {
+    fn from(widget: MenuSurface) -> Self {
+        let MenuSurface { html, anchor } = widget;
+        match anchor {
+            Some(anchor) => html! {
+                <div class = { MenuSurface::ANCHOR_CLASS } style = "display: inline-block;">
+                    { anchor }
+                    { html }
+                </div>
+            },
+            None => html,
+        }
+    }
+}
+
+impl ToHtml for MenuSurface {
+    fn to_html(&self) -> Html {
+        self.clone().into()
+    }
+
+    fn into_html(self) -> Html {
+        self.into()
+    }
+}
@@ -1,13 +1,17 @@
 use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
 
+use yew::html::onchange;
 use yew::virtual_dom::AttrValue;
-use yew::{html, Callback, Html, MouseEvent, ToHtml};
+use yew::{html, Callback, Event, Html, MouseEvent, ToHtml};
 
+use crate::dom::JsCast;
 use crate::utils::{
     add_input_label, labeled_on_click, ripple_element, root_and_input_child_disabled, IntoWidgetWithVList,
     ManageChildren, VTagExt,
 };
-use crate::{console, MdcWidget, AUTO_INIT_ATTR};
+use crate::web_sys::HtmlInputElement;
+use crate::{console, Binding, MdcWidget, AUTO_INIT_ATTR};
 
 pub mod mdc {
     pub const TYPE_NAME: &str = "MDCRadio";
@@ -67,6 +71,13 @@ impl Radio {
         self
     }
 
+    pub fn value(mut self, value: impl Into<AttrValue>) -> Self {
+        if let Some(input) = self.root_tag_mut().find_child_tag_mut("input") {
+            input.set_attr("value", value);
+        }
+        self
+    }
+
     pub fn label(self, label: impl Into<Html>) -> Self {
         add_input_label(self, label).unwrap_or_else(|widget| {
             console::error!(format!("Could not find input tag's id attribute for {}", Self::NAME));
@@ -95,6 +106,25 @@ impl Radio {
         labeled_on_click(&mut self, callback.into());
         self
     }
+
+    /// Binds the radio to the model: the radio is checked when the model equals `value`, and
+    /// sets the model to `value` when it is checked by the user.
+    pub fn bind<T: PartialEq + Clone + 'static>(mut self, binding: impl Into<Binding<T>>, value: T) -> Self {
+        let (current, on_change) = binding.into().into_parts();
+        self = self.checked(current == value);
+        if let Some(input) = self.root_tag_mut().find_child_tag_mut("input") {
+            input.add_listener(Rc::new(onchange::Wrapper::new(Callback::from(move |event: Event| {
+                if event
+                    .target()
+                    .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                    .is_some_and(|input| input.checked())
+                {
+                    on_change.emit(value.clone());
+                }
+            }))));
+        }
+        self
+    }
 }
 
 impl MdcWidget for Radio {
@@ -1,13 +1,34 @@
 use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
 
+use const_format::concatcp;
+use yew::html::onclick;
 use yew::virtual_dom::VTag;
 use yew::{classes, html, Callback, Html, MouseEvent, ToHtml};
 
-use crate::utils::{labeled_on_click, IntoWidgetWithVList, ManageChildren, VTagExt};
-use crate::{MdcWidget, AUTO_INIT_ATTR};
+use crate::binding::push_after_render;
+use crate::dom::JsCast;
+use crate::utils::{labeled_on_click, mdc_object, IntoWidgetWithVList, ManageChildren, VTagExt};
+use crate::{Binding, Element, MdcWidget, AUTO_INIT_ATTR};
 
 pub mod mdc {
+    use wasm_bindgen::prelude::*;
+
     pub const TYPE_NAME: &str = "MDCSwitch";
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_name = MDCSwitch)]
+        pub type Switch;
+
+        /// Returns whether the switch is selected ("on").
+        #[wasm_bindgen(method, getter)]
+        pub fn selected(this: &Switch) -> bool;
+
+        /// Selects or unselects the switch.
+        #[wasm_bindgen(method, setter)]
+        pub fn set_selected(this: &Switch, selected: bool);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -163,6 +184,38 @@ impl Switch {
         self
     }
 
+    /// Binds the selected state to the model.
+    pub fn bind(mut self, binding: impl Into<Binding<bool>>) -> Self {
+        let (on, on_change) = binding.into().into_parts();
+        self = self.turn(on);
+        let root = self.root_tag_mut();
+        push_after_render(root, move |root| {
+            if let Some(switch) = mdc_object(root, mdc::TYPE_NAME) {
+                let switch = switch.unchecked_into::<mdc::Switch>();
+                if switch.selected() != on {
+                    switch.set_selected(on);
+                }
+            }
+        });
+        // The switch listener is run before the MDC one, so the switch is not toggled yet
+        root.add_listener(Rc::new(onclick::Wrapper::new(Callback::from(
+            move |event: MouseEvent| {
+                let root = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .and_then(|target| target.closest(concatcp!(".", Switch::CLASS)).ok().flatten());
+                if let Some(root) = root {
+                    let on = match mdc_object(&root, mdc::TYPE_NAME) {
+                        Some(switch) => switch.unchecked_into::<mdc::Switch>().selected(),
+                        None => root.get_attribute("aria-checked").as_deref() == Some("true"),
+                    };
+                    on_change.emit(!on);
+                }
+            },
+        ))));
+        self
+    }
+
     fn icons(&mut self) -> &mut VTag {
         let handle = self
             .root_tag_mut()
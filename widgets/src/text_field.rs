@@ -1,14 +1,23 @@
+use std::error::Error;
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
+use std::{fmt, mem};
 
-use yew::html::{onclick, oninput};
+use yew::html::{onblur, onclick, oninput};
 use yew::virtual_dom::{AttrValue, VTag};
-use yew::{classes, html, Callback, Html, InputEvent, MouseEvent, ToHtml};
+use yew::{classes, html, Callback, FocusEvent, Html, InputEvent, MouseEvent, ToHtml};
 
+use crate::binding::push_after_render;
+use crate::dom::existing::JsObjectAccess;
+use crate::dom::{self, JsCast};
 use crate::floating_label::FloatingLabel;
 use crate::notched_outline::NotchedOutline;
-use crate::utils::{IntoWidgetWithVList, ManageChildren, VTagExt};
-use crate::{line_ripple, MdcObject, MdcWidget, AUTO_INIT_ATTR};
+use crate::utils::{mdc_object, IntoWidgetWithVList, ManageChildren, VTagExt};
+use crate::web_sys::{Element, Event, EventInit, HtmlInputElement, HtmlTextAreaElement};
+use crate::{
+    console, js_sys, line_ripple, Binding, IconButton, Mask, MdcObject, MdcWidget, ValidateOn, Validator,
+    AUTO_INIT_ATTR,
+};
 
 pub mod mdc {
     use wasm_bindgen::prelude::*;
@@ -48,6 +57,34 @@ pub mod mdc {
         /// Adjusts the dimensions and positions for all sub-elements.
         #[wasm_bindgen(method)]
         pub fn layout(this: &TextField);
+
+        /// Returns the custom validity state, if set; otherwise, the result of a native validity check.
+        #[wasm_bindgen(method, getter)]
+        pub fn valid(this: &TextField) -> bool;
+
+        /// Sets the custom validity state of the text field.
+        #[wasm_bindgen(method, setter)]
+        pub fn set_valid(this: &TextField, valid: bool);
+
+        /// Enables or disables the use of native validation. Use this for custom validation.
+        #[wasm_bindgen(method, setter = useNativeValidation)]
+        pub fn set_use_native_validation(this: &TextField, use_native_validation: bool);
+
+        /// Returns the character counter component of the text field, if it exists.
+        #[wasm_bindgen(method, getter = characterCounter)]
+        pub fn character_counter(this: &TextField) -> Option<CharacterCounter>;
+
+        #[wasm_bindgen(js_name = MDCTextFieldCharacterCounter, js_namespace = ["mdc", "textField"])]
+        pub type CharacterCounter;
+
+        #[wasm_bindgen(method, getter)]
+        pub fn foundation(this: &CharacterCounter) -> CharacterCounterFoundation;
+
+        pub type CharacterCounterFoundation;
+
+        /// Sets the `current / max` content of the counter, the current length is clamped to max.
+        #[wasm_bindgen(method, js_name = setCounterValue)]
+        pub fn set_counter_value(this: &CharacterCounterFoundation, current_length: u32, max_length: u32);
     }
 }
 
@@ -70,10 +107,104 @@ impl TextFieldStyle {
     }
 }
 
+/// The kind of the text field input, which defines its `type` and `inputmode` attributes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InputKind {
+    Text,
+    Password,
+    Email,
+    Number,
+    Date,
+    Search,
+    Tel,
+    Url,
+}
+
+impl InputKind {
+    pub fn type_attr(&self) -> &'static str {
+        match self {
+            InputKind::Text => "text",
+            InputKind::Password => "password",
+            InputKind::Email => "email",
+            InputKind::Number => "number",
+            InputKind::Date => "date",
+            InputKind::Search => "search",
+            InputKind::Tel => "tel",
+            InputKind::Url => "url",
+        }
+    }
+
+    /// Returns the virtual keyboard hint suitable for the kind.
+    pub fn input_mode(&self) -> Option<&'static str> {
+        match self {
+            InputKind::Email => Some("email"),
+            InputKind::Number => Some("decimal"),
+            InputKind::Search => Some("search"),
+            InputKind::Tel => Some("tel"),
+            InputKind::Url => Some("url"),
+            InputKind::Text | InputKind::Password | InputKind::Date => None,
+        }
+    }
+}
+
+/// The error of reading a typed value of the text field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueError {
+    /// The input is empty.
+    Empty,
+    /// The input value can't be parsed to the requested type.
+    Invalid(String),
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueError::Empty => write!(f, "The value is empty"),
+            ValueError::Invalid(value) => write!(f, "The value '{}' is invalid", value),
+        }
+    }
+}
+
+impl Error for ValueError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CharCounter {
+    Max(usize),
+    Soft(usize),
+    CountOnly,
+}
+
+impl CharCounter {
+    fn limit(&self) -> Option<usize> {
+        match self {
+            Self::Max(limit) | Self::Soft(limit) => Some(*limit),
+            Self::CountOnly => None,
+        }
+    }
+
+    fn is_exceeded(&self, count: usize) -> bool {
+        matches!(self, Self::Soft(limit) if count > *limit)
+    }
+
+    fn content(&self, count: usize) -> String {
+        match self {
+            Self::Max(max) => format!("{} / {}", count.min(*max), max),
+            Self::Soft(limit) => format!("{} / {}", count, limit),
+            Self::CountOnly => count.to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TextField {
     html: Html,
     style: TextFieldStyle,
+    validators: Vec<Validator>,
+    validate_on: ValidateOn,
+    required: bool,
+    char_counter: Option<CharCounter>,
+    mask: Option<Mask>,
+    input_callbacks: Vec<Callback<InputEvent>>,
 }
 
 impl TextField {
@@ -81,6 +212,7 @@ impl TextField {
     pub const INPUT_CLASS: &'static str = "mdc-text-field__input";
     pub const RIPPLE_CLASS: &'static str = "mdc-text-field__ripple";
     pub const DISABLED_CLASS: &'static str = "mdc-text-field--disabled";
+    pub const INVALID_CLASS: &'static str = "mdc-text-field--invalid";
     pub const HELPER_LINE_CLASS: &'static str = "mdc-text-field-helper-line";
     pub const CHARACTER_COUNTER_CLASS: &'static str = "mdc-text-field-character-counter";
     pub const WITH_LEADING_ICON_CLASS: &'static str = "mdc-text-field--with-leading-icon";
@@ -90,6 +222,32 @@ impl TextField {
     pub const TRAILING_ICON_CLASS: &'static str = "mdc-text-field__icon--trailing";
     pub const NO_LABEL_CLASS: &'static str = "mdc-text-field--no-label";
     pub const WITH_LABEL_FLOATING_CLASS: &'static str = "mdc-text-field--label-floating";
+    pub const TEXTAREA_CLASS: &'static str = "mdc-text-field--textarea";
+    pub const RESIZER_CLASS: &'static str = "mdc-text-field__resizer";
+    pub const WITH_INTERNAL_COUNTER_CLASS: &'static str = "mdc-text-field--with-internal-counter";
+    pub const PASSWORD_TOGGLE_CLASS: &'static str = "yew-mdc-text-field__password-toggle";
+    pub const AFFIX_CLASS: &'static str = "mdc-text-field__affix";
+    pub const PREFIX_CLASS: &'static str = "mdc-text-field__affix--prefix";
+    pub const SUFFIX_CLASS: &'static str = "mdc-text-field__affix--suffix";
+    /// The counter without a hard limit, which is not managed by MDC
+    pub const CHARACTER_COUNT_CLASS: &'static str = "yew-mdc-text-field-character-count";
+    pub const CHARACTER_COUNT_EXCEEDED_CLASS: &'static str = "yew-mdc-text-field-character-count--exceeded";
+    pub const CHAR_LIMIT_ATTR: &'static str = "data-char-limit";
+    /// The raw value of the masked input
+    pub const RAW_VALUE_ATTR: &'static str = "data-raw-value";
+
+    fn with_html(html: Html, style: TextFieldStyle) -> Self {
+        Self {
+            html,
+            style,
+            validators: Vec::new(),
+            validate_on: ValidateOn::default(),
+            required: false,
+            char_counter: None,
+            mask: None,
+            input_callbacks: Vec::new(),
+        }
+    }
 
     fn simple() -> Html {
         let mut html = html! {
@@ -102,22 +260,85 @@ impl TextField {
     }
 
     pub fn filled() -> Self {
-        let text_field = Self {
-            html: Self::simple(),
-            style: TextFieldStyle::Filled,
-        };
+        let text_field = Self::with_html(Self::simple(), TextFieldStyle::Filled);
         text_field.ripple(true).class(TextFieldStyle::Filled.class())
     }
 
     pub fn outlined() -> Self {
-        let mut text_field = Self {
-            html: Self::simple(),
-            style: TextFieldStyle::Outlined,
-        };
+        let mut text_field = Self::with_html(Self::simple(), TextFieldStyle::Outlined);
         text_field.root_tag_mut().add_child(NotchedOutline::new().into());
         text_field.class(TextFieldStyle::Outlined.class())
     }
 
+    fn simple_textarea() -> Html {
+        let mut html = html! {
+            <label class = { classes!(Self::CLASS, Self::TEXTAREA_CLASS) }>
+                <span class = { Self::RESIZER_CLASS }>
+                    <textarea class = { Self::INPUT_CLASS } rows = "8" cols = "40"></textarea>
+                </span>
+            </label>
+        };
+        html.root_tag_mut().unwrap(/* root tag already exists */).set_attr(AUTO_INIT_ATTR, mdc::TYPE_NAME);
+        html
+    }
+
+    /// Creates multi-line text field of the given style, which renders `textarea` with a resizer.
+    pub fn textarea(style: TextFieldStyle) -> Self {
+        let mut text_field = Self::with_html(Self::simple_textarea(), style);
+        match style {
+            TextFieldStyle::Filled => text_field.ripple(true).class(style.class()),
+            TextFieldStyle::Outlined => {
+                text_field.root_tag_mut().insert_child(0, NotchedOutline::new());
+                text_field.class(style.class())
+            },
+        }
+    }
+
+    pub fn is_textarea(&self) -> bool {
+        self.root_tag().is_contains_class(Self::TEXTAREA_CLASS)
+    }
+
+    /// Sets the number of visible text lines of the textarea.
+    pub fn rows(mut self, rows: usize) -> Self {
+        if let Some(textarea) = self.root_tag_mut().find_child_tag_recursively_mut("textarea") {
+            textarea.set_attr("rows", rows.to_string());
+        }
+        self
+    }
+
+    /// Sets the visible width of the textarea in average character widths.
+    pub fn cols(mut self, cols: usize) -> Self {
+        if let Some(textarea) = self.root_tag_mut().find_child_tag_recursively_mut("textarea") {
+            textarea.set_attr("cols", cols.to_string());
+        }
+        self
+    }
+
+    /// Grows the textarea with its content from `min_rows` up to `max_rows`. The initial value is
+    /// measured after the first render.
+    pub fn auto_grow(mut self, min_rows: u32, max_rows: u32) -> Self {
+        if let Some(textarea) = self.root_tag_mut().find_child_tag_recursively_mut("textarea") {
+            let min_rows = min_rows.clamp(1, max_rows.max(1));
+            textarea.set_attr("rows", min_rows.to_string());
+            textarea.add_listener(Rc::new(oninput::Wrapper::new(Callback::from(
+                move |event: InputEvent| {
+                    if let Some(textarea) = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<HtmlTextAreaElement>().ok())
+                    {
+                        grow_textarea(&textarea, min_rows, max_rows);
+                    }
+                },
+            ))));
+            push_after_render(textarea, move |textarea| {
+                if let Some(textarea) = textarea.dyn_ref::<HtmlTextAreaElement>() {
+                    grow_textarea(textarea, min_rows, max_rows);
+                }
+            });
+        }
+        self
+    }
+
     /// Returns the input's value.
     pub fn get_value(id: impl AsRef<str>) -> String {
         Self::get_mdc_object(id).value()
@@ -125,7 +346,76 @@ impl TextField {
 
     /// Sets the input's value.
     pub fn set_value(id: impl AsRef<str>, value: impl AsRef<str>) {
-        Self::get_mdc_object(id).set_value(value.as_ref());
+        Self::get_mdc_object(id.as_ref()).set_value(value.as_ref());
+        Self::update_char_counter_existing(id);
+    }
+
+    /// Returns the raw value of the masked input, or the input's value if it is not masked.
+    pub fn get_raw_value(id: impl AsRef<str>) -> String {
+        let root = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        root.query_selector(&format!(".{}", Self::INPUT_CLASS))
+            .ok()
+            .flatten()
+            .and_then(|input| input.get_attribute(Self::RAW_VALUE_ATTR))
+            .unwrap_or_else(|| Self::get_value(id))
+    }
+
+    /// Sets the raw value of the masked input, which is formatted by the mask as if it was typed.
+    pub fn set_raw_value(id: impl AsRef<str>, raw: impl AsRef<str>) {
+        Self::set_value(id.as_ref(), raw);
+        let root = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        if let Ok(Some(input)) = root.query_selector(&format!(".{}", Self::INPUT_CLASS)) {
+            let init = EventInit::new();
+            init.set_bubbles(true);
+            if let Ok(event) = Event::new_with_event_init_dict("input", &init) {
+                let _ = input.dispatch_event(&event);
+            }
+        }
+    }
+
+    /// Returns the input's value parsed as a number.
+    pub fn get_number(id: impl AsRef<str>) -> Result<f64, ValueError> {
+        let value = Self::get_value(id);
+        let value = value.trim();
+        if value.is_empty() {
+            Err(ValueError::Empty)
+        } else {
+            value
+                .parse()
+                .ok()
+                .filter(|number: &f64| number.is_finite())
+                .ok_or_else(|| ValueError::Invalid(value.into()))
+        }
+    }
+
+    /// Returns the input's value parsed as a date. The value of the `date` input has the
+    /// `YYYY-MM-DD` format, which is parsed as UTC midnight.
+    pub fn get_date(id: impl AsRef<str>) -> Result<js_sys::Date, ValueError> {
+        let value = Self::get_value(id);
+        let value = value.trim();
+        if value.is_empty() {
+            Err(ValueError::Empty)
+        } else {
+            let date = js_sys::Date::new(&value.into());
+            if date.get_time().is_nan() {
+                Err(ValueError::Invalid(value.into()))
+            } else {
+                Ok(date)
+            }
+        }
+    }
+
+    /// Runs the validators on the current value of the existing text field and shows the result.
+    pub fn validate_existing(id: impl AsRef<str>, validators: &[Validator]) -> Result<(), String> {
+        let root = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        validate_element(&root, validators)
+    }
+
+    /// Marks the existing text field as invalid and shows the error in its helper text, or
+    /// restores the valid state if the error is `None`.
+    pub fn set_error_existing(id: impl AsRef<str>, error: Option<&str>) {
+        let root = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        show_validity(&root, error);
     }
 
     /// Returns whether or not the input is disabled.
@@ -183,11 +473,15 @@ impl TextField {
 
         match self.style {
             TextFieldStyle::Filled => {
-                let idx = self
-                    .root_tag()
-                    .find_child_tag_idx("input")
-                    .map(|idx| idx + 1)
-                    .unwrap_or(0);
+                let root = self.root_tag();
+                let idx = match root.find_child_contains_class_idx(Self::RESIZER_CLASS) {
+                    Some(idx) => idx,
+                    None => root
+                        .find_child_contains_class_idx(Self::SUFFIX_CLASS)
+                        .or_else(|| root.find_child_tag_idx("input"))
+                        .map(|idx| idx + 1)
+                        .unwrap_or(0),
+                };
                 self.root_tag_mut().insert_child(idx, label);
                 if let Some(input_tag) = self.input_tag_mut() {
                     input_tag.set_attr("aria-labelledby", label_id);
@@ -222,10 +516,99 @@ impl TextField {
         self
     }
 
+    /// Sets the `type` and the `inputmode` attributes of the input according to the kind.
+    pub fn input_kind(mut self, kind: InputKind) -> Self {
+        if let Some(input) = self.input_tag_mut() {
+            input.set_attr("type", kind.type_attr());
+            match kind.input_mode() {
+                Some(mode) => input.set_attr("inputmode", mode),
+                None => {
+                    input.remove_attr_or_prop("inputmode");
+                },
+            }
+        }
+        self
+    }
+
+    /// Sets the minimum value of the `number` or `date` input.
+    pub fn min(self, min: impl ToString) -> Self {
+        self.input_attr("min", min.to_string())
+    }
+
+    /// Sets the maximum value of the `number` or `date` input.
+    pub fn max(self, max: impl ToString) -> Self {
+        self.input_attr("max", max.to_string())
+    }
+
+    /// Sets the granularity of the `number` or `date` input value.
+    pub fn step(self, step: impl ToString) -> Self {
+        self.input_attr("step", step.to_string())
+    }
+
+    /// Overrides the virtual keyboard hint, e.g. `numeric` or `decimal`.
+    pub fn input_mode(self, mode: impl Into<AttrValue>) -> Self {
+        self.input_attr("inputmode", mode)
+    }
+
+    /// Sets the autofill hint of the input, e.g. `email`, `current-password` or `new-password`.
+    pub fn autocomplete(self, autocomplete: impl Into<AttrValue>) -> Self {
+        self.input_attr("autocomplete", autocomplete)
+    }
+
+    pub fn input_attr(mut self, attr: &'static str, value: impl Into<AttrValue>) -> Self {
+        if let Some(input) = self.input_tag_mut() {
+            input.set_attr(attr, value);
+        }
+        self
+    }
+
+    /// Makes the input a password input with a trailing button which shows and hides the password.
+    pub fn password_toggle(self) -> Self {
+        let toggle = IconButton::new()
+            .toggle("visibility_off", "visibility")
+            .class(classes!(Self::ICON_CLASS, Self::TRAILING_ICON_CLASS, Self::PASSWORD_TOGGLE_CLASS).to_string())
+            .attr("type", "button")
+            .attr("aria-label", "Show password")
+            .on_click(|event: MouseEvent| {
+                let Some(button) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .and_then(|target| {
+                        target
+                            .closest(&format!(".{}", Self::PASSWORD_TOGGLE_CLASS))
+                            .ok()
+                            .flatten()
+                    })
+                else {
+                    return;
+                };
+                if let Some(input) = button
+                    .closest(&format!(".{}", Self::CLASS))
+                    .ok()
+                    .flatten()
+                    .and_then(|root| root.query_selector("input").ok().flatten())
+                    .and_then(|input| input.dyn_into::<HtmlInputElement>().ok())
+                {
+                    let is_hidden = input.type_() == InputKind::Password.type_attr();
+                    input.set_type(if is_hidden {
+                        InputKind::Text.type_attr()
+                    } else {
+                        InputKind::Password.type_attr()
+                    });
+                    let _ =
+                        button.set_attribute("aria-label", if is_hidden { "Hide password" } else { "Show password" });
+                }
+            });
+        self.input_kind(InputKind::Password).trailing_tile(toggle)
+    }
+
     pub fn leading_tile(mut self, tile: impl Into<Html>) -> Self {
         self.add_class(Self::WITH_LEADING_ICON_CLASS);
         let root = self.root_tag_mut();
-        let index = root.find_child_tag_idx("input").unwrap_or_default();
+        let index = root
+            .find_child_contains_class_idx(Self::PREFIX_CLASS)
+            .or_else(|| root.find_child_tag_idx("input"))
+            .unwrap_or_default();
         root.insert_child(index, tile);
         self
     }
@@ -234,13 +617,42 @@ impl TextField {
         self.add_class(Self::WITH_TRAILING_ICON_CLASS);
         let root = self.root_tag_mut();
         let index = root
-            .find_child_tag_idx("input")
+            .find_child_contains_class_idx(Self::SUFFIX_CLASS)
+            .or_else(|| root.find_child_tag_idx("input"))
             .map(|index| index + 1)
             .unwrap_or_default();
         root.insert_child(index, tile);
         self
     }
 
+    /// Adds the prefix text right before the input, like a currency sign. The prefix is shown
+    /// only when the label floats above the input.
+    pub fn prefix(self, text: impl Into<Html>) -> Self {
+        self.affix(Self::PREFIX_CLASS, text.into(), 0)
+    }
+
+    /// Adds the suffix text right after the input, like a unit or a domain. The suffix is shown
+    /// only when the label floats above the input.
+    pub fn suffix(self, text: impl Into<Html>) -> Self {
+        self.affix(Self::SUFFIX_CLASS, text.into(), 1)
+    }
+
+    fn affix(mut self, class: &'static str, text: Html, offset: usize) -> Self {
+        if self.is_textarea() {
+            console::error!(format!("The {} textarea does not support affixes", Self::NAME));
+            return self;
+        }
+
+        let root = self.root_tag_mut();
+        root.remove_child_contains_class(class);
+        if let Some(index) = root.find_child_tag_idx("input") {
+            root.insert_child(index + offset, html! {
+                <span class = { classes!(Self::AFFIX_CLASS, class) }>{ text }</span>
+            });
+        }
+        self
+    }
+
     pub fn leading_icon(self, name: impl Into<String>) -> Self {
         self.leading_tile(
             html! { <i class = { classes!("material-icons", Self::ICON_CLASS, Self::LEADING_ICON_CLASS) }>{ name.into() }</i> },
@@ -261,6 +673,63 @@ impl TextField {
         self
     }
 
+    /// Adds the validator of the value. The validators run on blur, see [`TextField::validate_on`].
+    /// An invalid value marks the field as invalid, shakes the label and shows the error in the
+    /// helper text.
+    pub fn validator(mut self, validator: Validator) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Sets when the validators run. Blur always runs them, [`ValidateOn::Input`] also runs them
+    /// on each input.
+    pub fn validate_on(mut self, validate_on: ValidateOn) -> Self {
+        self.validate_on = validate_on;
+        self
+    }
+
+    /// Marks the field as required and adds the [`Validator::required`] validator.
+    pub fn required(mut self) -> Self {
+        if !self.required {
+            self.required = true;
+            self.validators.insert(0, Validator::required());
+            if let Some(input) = self.input_tag_mut() {
+                input.set_attr("aria-required", "true");
+            }
+        }
+        self
+    }
+
+    pub fn get_validators(&self) -> &[Validator] {
+        &self.validators
+    }
+
+    fn add_validation_listeners(mut self) -> Self {
+        let validators = Rc::new(self.validators.clone());
+        let validate = move |target: Option<crate::EventTarget>| {
+            if let Some(root) = target
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|input| input.closest(&format!(".{}", Self::CLASS)).ok().flatten())
+            {
+                let _ = validate_element(&root, &validators);
+            }
+        };
+
+        let validate_on = self.validate_on;
+        if let Some(input) = self.input_tag_mut() {
+            if validate_on == ValidateOn::Input {
+                let validate = validate.clone();
+                input.add_listener(Rc::new(oninput::Wrapper::new(Callback::from(
+                    move |event: InputEvent| validate(event.target()),
+                ))));
+            }
+            input.add_listener(Rc::new(onblur::Wrapper::new(Callback::from(
+                move |event: FocusEvent| validate(event.target()),
+            ))));
+        }
+        self
+    }
+
     pub fn helper_text(mut self, mut helper_text: HelperText) -> Self {
         let id = self.root_id();
         let helper_id = match helper_text.root_tag().attr("id") {
@@ -290,29 +759,105 @@ impl TextField {
         self
     }
 
+    /// Adds the character counter with the `max_length` limit, which also limits the input length.
     pub fn char_counter(mut self, max_length: usize) -> Self {
-        let helper_string = format!("0 / {}", max_length);
-
         if let Some(input_tag) = self.input_tag_mut() {
             input_tag.set_attr("maxlength", format!("{}", max_length));
         }
-        if let Some(helper_line) = self.html_mut().find_child_contains_class_mut(Self::HELPER_LINE_CLASS) {
+        self.char_counter = Some(CharCounter::Max(max_length));
+        self
+    }
+
+    /// Adds the character counter with the `limit`, which does not block input. The exceeded
+    /// limit is highlighted with the error color.
+    pub fn char_counter_soft(mut self, limit: usize) -> Self {
+        if let Some(input_tag) = self.input_tag_mut() {
+            input_tag.remove_attr_or_prop("maxlength");
+        }
+        self.char_counter = Some(CharCounter::Soft(limit));
+        self
+    }
+
+    /// Adds the character counter without a limit, which shows only the current count.
+    pub fn char_count(mut self) -> Self {
+        if let Some(input_tag) = self.input_tag_mut() {
+            input_tag.remove_attr_or_prop("maxlength");
+        }
+        self.char_counter = Some(CharCounter::CountOnly);
+        self
+    }
+
+    /// Updates the character counter of the existing text field according to the current value.
+    pub fn update_char_counter_existing(id: impl AsRef<str>) {
+        let root = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        update_char_counter(&root);
+    }
+
+    fn add_char_counter(mut self, counter: CharCounter) -> Self {
+        let count = self
+            .input_tag_mut()
+            .and_then(|input| input.value().map(|value| char_count(value)))
+            .unwrap_or_default();
+        let is_internal = self.is_textarea();
+
+        // MDC manages the counter only if the input has the `maxlength` attribute, so the counter
+        // without a hard limit has another class to keep MDC from finding it. Its style is in the
+        // `yew-mdc-widgets.css` stylesheet.
+        let class = match counter {
+            CharCounter::Max(_) => classes!(Self::CHARACTER_COUNTER_CLASS),
+            CharCounter::Soft(_) | CharCounter::CountOnly => {
+                let mut class = classes!(Self::CHARACTER_COUNT_CLASS, "mdc-typography--caption");
+                if counter.is_exceeded(count) {
+                    class.push(Self::CHARACTER_COUNT_EXCEEDED_CLASS);
+                }
+                class
+            },
+        };
+        let limit = counter.limit().map(|limit| limit.to_string());
+        let content = counter.content(count);
+
+        if is_internal {
+            self.root_tag_mut()
+                .add_class_if_needed(Self::WITH_INTERNAL_COUNTER_CLASS);
+            if let Some(resizer) = self.root_tag_mut().find_child_contains_class_mut(Self::RESIZER_CLASS) {
+                resizer.add_child(html! {
+                    <span { class } data-char-limit = { limit }>{ content }</span>
+                });
+            }
+        } else if let Some(helper_line) = self.html_mut().find_child_contains_class_mut(Self::HELPER_LINE_CLASS) {
             helper_line.add_child(html! {
-                <div class = { Self::CHARACTER_COUNTER_CLASS }>{ helper_string }</div>
+                <div { class } data-char-limit = { limit }>{ content }</div>
             });
         } else {
             self = self.into_widget_with_v_list();
             self.html_mut().add_child(html! {
                 <div class = { Self::HELPER_LINE_CLASS }>
-                    <div class = { Self::CHARACTER_COUNTER_CLASS }>{ helper_string }</div>
+                    <div { class } data-char-limit = { limit }>{ content }</div>
                 </div>
             });
         }
+
+        if let Some(input) = self.input_tag_mut() {
+            input.add_listener(Rc::new(oninput::Wrapper::new(Callback::from(|event: InputEvent| {
+                if let Some(root) = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Element>().ok())
+                    .and_then(|input| input.closest(&format!(".{}", Self::CLASS)).ok().flatten())
+                {
+                    update_char_counter(&root);
+                }
+            }))));
+        }
         self
     }
 
+    /// Returns the `input` tag, or the `textarea` tag of the multi-line text field.
     pub fn input_tag_mut(&mut self) -> Option<&mut VTag> {
-        self.root_tag_mut().find_child_tag_mut("input")
+        if self.is_textarea() {
+            self.root_tag_mut().find_child_tag_recursively_mut("textarea")
+        } else {
+            self.root_tag_mut().find_child_tag_mut("input")
+        }
     }
 
     pub fn root_id(&self) -> AttrValue {
@@ -323,14 +868,234 @@ impl TextField {
         self.listener(Rc::new(onclick::Wrapper::new(callback.into())))
     }
 
+    /// Adds the input listener. Unlike the other listeners, the input listeners are attached to
+    /// the input when the widget is converted into `Html`, after the mask, the validation and the
+    /// character counter listeners. So the input listeners are called in the order they are added,
+    /// but after the built-in ones, and see the value already formatted by the mask.
     pub fn on_input(mut self, callback: impl Into<Callback<InputEvent>>) -> Self {
+        self.input_callbacks.push(callback.into());
+        self
+    }
+
+    /// Sets the mask, which formats the value while typing. The raw value is available with
+    /// [`TextField::get_raw_value`].
+    pub fn mask(mut self, mask: impl Into<Mask>) -> Self {
+        self.mask = Some(mask.into());
+        self
+    }
+
+    /// Binds the input's value to the model. The model of the masked input holds the raw value.
+    pub fn bind(mut self, binding: impl Into<Binding<String>>) -> Self {
+        let (value, on_change) = binding.into().into_parts();
+        if let Some(input) = self.input_tag_mut() {
+            // The label is floated by the MDC object, which gets the value after render
+            input.set_value(Some(AttrValue::from(value)));
+        }
+        push_after_render(self.root_tag(), |root| {
+            let is_focused = dom::existing::document()
+                .active_element()
+                .is_some_and(|active| root.contains(Some(&active)));
+            if !is_focused {
+                if let Some(text_field) = mdc_object(root, mdc::TYPE_NAME) {
+                    text_field
+                        .unchecked_into::<mdc::TextField>()
+                        .set_value(&input_value(root));
+                    update_char_counter(root);
+                }
+            }
+        });
+        self.on_input(move |event: InputEvent| {
+            if let Some(input) = event.target().and_then(|target| target.dyn_into::<Element>().ok()) {
+                let value = input.get_attribute(Self::RAW_VALUE_ATTR).unwrap_or_else(|| {
+                    input
+                        .closest(&format!(".{}", Self::CLASS))
+                        .ok()
+                        .flatten()
+                        .map(|root| input_value(&root))
+                        .unwrap_or_default()
+                });
+                on_change.emit(value);
+            }
+        })
+    }
+
+    fn add_mask(mut self, mask: Mask) -> Self {
         if let Some(input) = self.input_tag_mut() {
-            input.add_listener(Rc::new(oninput::Wrapper::new(callback.into())));
+            let raw = input.value().map(|value| mask.parse(value)).unwrap_or_default();
+            if !raw.is_empty() {
+                input.set_value(Some(mask.format(&raw)));
+            }
+            input.set_attr(Self::RAW_VALUE_ATTR, raw);
+            input.add_listener(Rc::new(oninput::Wrapper::new(Callback::from(
+                move |event: InputEvent| {
+                    if let Some(input) = event
+                        .target()
+                        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+                    {
+                        apply_mask(&input, &mask);
+                    }
+                },
+            ))));
         }
         self
     }
 }
 
+pub(crate) fn input_value(root: &Element) -> String {
+    match root.query_selector(&format!(".{}", TextField::INPUT_CLASS)) {
+        Ok(Some(input)) => match input.dyn_into::<HtmlInputElement>() {
+            Ok(input) => input.value(),
+            Err(input) => input
+                .dyn_into::<HtmlTextAreaElement>()
+                .map(|textarea| textarea.value())
+                .unwrap_or_default(),
+        },
+        _ => String::new(),
+    }
+}
+
+pub(crate) fn set_input_value(root: &Element, value: &str) {
+    if let Ok(Some(input)) = root.query_selector(&format!(".{}", TextField::INPUT_CLASS)) {
+        match input.dyn_into::<HtmlInputElement>() {
+            Ok(input) => input.set_value(value),
+            Err(input) => {
+                if let Ok(textarea) = input.dyn_into::<HtmlTextAreaElement>() {
+                    textarea.set_value(value);
+                }
+            },
+        }
+    }
+}
+
+fn apply_mask(input: &HtmlInputElement, mask: &Mask) {
+    let value = input.value();
+    let raw = mask.parse(&value);
+    let masked = mask.format(&raw);
+    let _ = input.set_attribute(TextField::RAW_VALUE_ATTR, &raw);
+
+    if masked != value {
+        // The caret stays after the same number of raw characters
+        let caret = input.selection_start().ok().flatten().map(|caret| {
+            let prefix: String = char::decode_utf16(value.encode_utf16().take(caret as usize))
+                .map(|ch| ch.unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect();
+            let position = mask.caret_position(&masked, mask.parse(&prefix).chars().count());
+            masked.chars().take(position).map(char::len_utf16).sum::<usize>() as u32
+        });
+
+        input.set_value(&masked);
+        if let Some(caret) = caret {
+            let _ = input.set_selection_range(caret, caret);
+        }
+    }
+}
+
+/// The length of the value, which is counted like the `maxlength` attribute does.
+fn char_count(value: &str) -> usize {
+    value.encode_utf16().count()
+}
+
+fn update_char_counter(root: &Element) {
+    let selector = format!(
+        ".{}, .{}",
+        TextField::CHARACTER_COUNTER_CLASS,
+        TextField::CHARACTER_COUNT_CLASS
+    );
+    let counter = root.query_selector(&selector).ok().flatten().or_else(|| {
+        root.next_element_sibling()
+            .filter(|helper_line| helper_line.class_list().contains(TextField::HELPER_LINE_CLASS))
+            .and_then(|helper_line| helper_line.query_selector(&selector).ok().flatten())
+    });
+    let counter = match counter {
+        Some(counter) => counter,
+        None => return,
+    };
+
+    let count = char_count(&input_value(root));
+    let limit = counter
+        .get_attribute(TextField::CHAR_LIMIT_ATTR)
+        .and_then(|limit| limit.parse().ok());
+    let kind = if counter.class_list().contains(TextField::CHARACTER_COUNTER_CLASS) {
+        CharCounter::Max(limit.unwrap_or_default())
+    } else {
+        limit.map_or(CharCounter::CountOnly, CharCounter::Soft)
+    };
+
+    if let CharCounter::Max(max) = kind {
+        let mdc_counter = Some(root.get(mdc::TYPE_NAME))
+            .filter(|text_field| !text_field.is_undefined())
+            .and_then(|text_field| text_field.unchecked_into::<mdc::TextField>().character_counter());
+        if let Some(mdc_counter) = mdc_counter {
+            mdc_counter.foundation().set_counter_value(count as u32, max as u32);
+            return;
+        }
+    }
+
+    counter.set_text_content(Some(&kind.content(count)));
+    let _ = counter
+        .class_list()
+        .toggle_with_force(TextField::CHARACTER_COUNT_EXCEEDED_CLASS, kind.is_exceeded(count));
+}
+
+pub(crate) fn validate_element(root: &Element, validators: &[Validator]) -> Result<(), String> {
+    let result = Validator::validate_all(validators, &input_value(root));
+    show_validity(root, result.as_ref().err().map(String::as_str));
+    result
+}
+
+fn show_validity(root: &Element, error: Option<&str>) {
+    let is_valid = error.is_none();
+
+    let text_field = root.get(mdc::TYPE_NAME);
+    if !text_field.is_undefined() {
+        let text_field = text_field.unchecked_into::<mdc::TextField>();
+        text_field.set_use_native_validation(false);
+        text_field.set_valid(is_valid);
+    }
+    let _ = if is_valid {
+        root.class_list().remove_1(TextField::INVALID_CLASS)
+    } else {
+        root.class_list().add_1(TextField::INVALID_CLASS)
+    };
+
+    let input = root
+        .query_selector(&format!(".{}", TextField::INPUT_CLASS))
+        .ok()
+        .flatten();
+    if let Some(input) = &input {
+        let _ = input.set_attribute("aria-invalid", if is_valid { "false" } else { "true" });
+    }
+
+    if !is_valid {
+        if let Ok(Some(label)) = root.query_selector(&format!(".{}", FloatingLabel::CLASS)) {
+            let _ = label.class_list().add_1(FloatingLabel::SHAKE_CLASS);
+        }
+    }
+
+    // The input can control several elements, like the helper text and the autocomplete listbox
+    let helper_text = input
+        .and_then(|input| input.get_attribute("aria-controls"))
+        .and_then(|controls| {
+            controls
+                .split_whitespace()
+                .filter_map(|id| dom::existing::document().get_element_by_id(id))
+                .find(|element| element.class_list().contains(HelperText::CLASS))
+        });
+    if let Some(helper_text) = helper_text {
+        HelperText::show_error(&helper_text, error);
+    }
+}
+
+/// Sets the least number of rows from the range, at which the textarea content is not scrolled.
+fn grow_textarea(textarea: &HtmlTextAreaElement, min_rows: u32, max_rows: u32) {
+    let mut rows = min_rows;
+    textarea.set_rows(rows);
+    while textarea.scroll_height() > textarea.client_height() && rows < max_rows {
+        rows += 1;
+        textarea.set_rows(rows);
+    }
+}
+
 impl MdcWidget for TextField {
     const NAME: &'static str = stringify!(TextField);
 
@@ -372,7 +1137,35 @@ impl DerefMut for TextField {
 }
 
 impl From<TextField> for Html {
-    fn from(widget: TextField) -> Self {
+    fn from(mut widget: TextField) -> Self {
+        if let Some(mask) = widget.mask.take() {
+            widget = widget.add_mask(mask);
+        }
+        if widget.required {
+            if let Some(label) = widget.find_child_contains_class_recursively_mut(FloatingLabel::CLASS) {
+                label.add_class_if_needed(FloatingLabel::REQUIRED_CLASS);
+            }
+        }
+        if !widget.validators.is_empty() {
+            widget = widget.add_validation_listeners();
+        }
+        if let Some(counter) = widget.char_counter {
+            widget = widget.add_char_counter(counter);
+        }
+        // Without the label, the affixes are always shown
+        let root = widget.root_tag_mut();
+        if root.is_some_child_contains_class(TextField::AFFIX_CLASS)
+            && root
+                .find_child_contains_class_recursively_mut(FloatingLabel::CLASS)
+                .is_none()
+        {
+            root.add_class_if_needed(TextField::NO_LABEL_CLASS);
+        }
+        for callback in mem::take(&mut widget.input_callbacks) {
+            if let Some(input) = widget.input_tag_mut() {
+                input.add_listener(Rc::new(oninput::Wrapper::new(callback)));
+            }
+        }
         widget.html
     }
 }
@@ -414,6 +1207,37 @@ impl HelperText {
         self.root_tag_mut().add_class(Self::VALIDATION_MSG_CLASS);
         self
     }
+
+    /// Replaces the text of the existing helper text element with the validation error, or
+    /// restores the original text if the error is `None`.
+    pub fn show_error(helper_text: &Element, error: Option<&str>) {
+        const DEFAULT_TEXT_ATTR: &str = "data-default-text";
+        const DEFAULT_CLASS_ATTR: &str = "data-default-class";
+
+        match error {
+            Some(error) => {
+                if !helper_text.has_attribute(DEFAULT_TEXT_ATTR) {
+                    let _ =
+                        helper_text.set_attribute(DEFAULT_TEXT_ATTR, &helper_text.text_content().unwrap_or_default());
+                    let _ = helper_text.set_attribute(DEFAULT_CLASS_ATTR, &helper_text.class_name());
+                }
+                helper_text.set_text_content(Some(error));
+                let _ = helper_text.class_list().add_1(Self::VALIDATION_MSG_CLASS);
+                let _ = helper_text.set_attribute("role", "alert");
+            },
+            None => {
+                if let Some(text) = helper_text.get_attribute(DEFAULT_TEXT_ATTR) {
+                    helper_text.set_text_content(Some(&text));
+                    if let Some(class) = helper_text.get_attribute(DEFAULT_CLASS_ATTR) {
+                        helper_text.set_class_name(&class);
+                    }
+                    let _ = helper_text.remove_attribute(DEFAULT_TEXT_ATTR);
+                    let _ = helper_text.remove_attribute(DEFAULT_CLASS_ATTR);
+                    let _ = helper_text.remove_attribute("role");
+                }
+            },
+        }
+    }
 }
 
 impl MdcWidget for HelperText {
@@ -0,0 +1,578 @@
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+use const_format::concatcp;
+use yew::html::{onclick, onkeydown};
+use yew::virtual_dom::{AttrValue, Key};
+use yew::{classes, html, Callback, Event, Html, KeyboardEvent, MouseEvent, ToHtml};
+
+use crate::binding::push_after_render;
+use crate::dom::existing::JsObjectAccess;
+use crate::dom::{self, JsCast, JsValue};
+use crate::utils::{emit_custom_event, ManageChildren, VTagExt};
+use crate::web_sys::HtmlElement;
+use crate::{console, CustomEvent, Element, List, ListItem, MdcWidget, MATERIAL_ICONS_CLASS};
+
+/// The node, which is expanded or collapsed by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeToggle {
+    pub key: String,
+    pub expanded: bool,
+}
+
+/// The node, which is selected or unselected by the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeSelection {
+    pub key: String,
+    pub selected: bool,
+}
+
+/// The node of the [`TreeView`], which is identified by the key in the events. The key must be
+/// unique in the tree.
+#[derive(Debug, Clone)]
+pub struct TreeNode {
+    key: AttrValue,
+    item: ListItem,
+    children: Vec<TreeNode>,
+    expanded: bool,
+    selected: bool,
+    lazy: bool,
+}
+
+impl TreeNode {
+    pub fn new(key: impl Into<AttrValue>) -> Self {
+        Self {
+            key: key.into(),
+            item: ListItem::new(),
+            children: Vec::new(),
+            expanded: false,
+            selected: false,
+            lazy: false,
+        }
+    }
+
+    /// Sets the list item, which shows the node. The expand toggle and the indentation are added
+    /// to the item.
+    pub fn item(mut self, item: ListItem) -> Self {
+        self.item = item;
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<Html>) -> Self {
+        self.item = self.item.text(text);
+        self
+    }
+
+    pub fn icon(mut self, name: impl Into<String>) -> Self {
+        self.item = self.item.icon(name);
+        self
+    }
+
+    pub fn child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = TreeNode>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Marks the node as expandable before its children are known. The children are requested by
+    /// [`TreeView::on_load`] on the first expansion, and shown when they are rendered. The node is
+    /// busy until it is rendered with the children or without the lazy mark.
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    fn render(self, position: Position, parent: Option<&AttrValue>, is_hidden: bool, rows: &mut Vec<Html>) {
+        let Self {
+            key,
+            mut item,
+            children,
+            expanded,
+            selected,
+            lazy,
+        } = self;
+        let is_expandable = lazy || !children.is_empty();
+
+        let root = item.root_tag_mut();
+        let toggle_idx = root
+            .find_child_contains_class_idx(ListItem::RIPPLE_CLASS)
+            .map(|idx| idx + 1)
+            .unwrap_or(0);
+        root.insert_child(toggle_idx, html! {
+            <span class = { classes!(TreeView::TOGGLE_CLASS, MATERIAL_ICONS_CLASS) } aria-hidden = "true">
+                { if is_expandable { "chevron_right" } else { "" } }
+            </span>
+        });
+
+        let mut item = item
+            .selected(selected)
+            .attr("role", "treeitem")
+            .attr(TreeView::KEY_ATTR, key.to_string())
+            .attr("aria-level", position.level.to_string())
+            .attr("aria-posinset", position.index.to_string())
+            .attr("aria-setsize", position.size.to_string())
+            .attr("aria-selected", selected.to_string())
+            .attr("tabindex", if position.is_first() { "0" } else { "-1" });
+        // The indentation is styled by the level property, so the own style of the item is kept
+        let level = format!("{}: {};", TreeView::LEVEL_VAR, position.level - 1);
+        let style = match item.root_tag().attr("style") {
+            Some(own) if !own.trim().is_empty() => format!("{}; {}", own.trim().trim_end_matches(';'), level),
+            _ => level,
+        };
+        item = item.attr("style", style);
+        if let Some(parent) = parent {
+            item = item.attr(TreeView::PARENT_ATTR, parent.to_string());
+        }
+        if is_expandable {
+            item = item.attr("aria-expanded", expanded.to_string());
+        }
+        if lazy && children.is_empty() {
+            item = item.attr(TreeView::LAZY_ATTR, "true");
+        }
+        if is_hidden {
+            item = item.attr("hidden", "hidden");
+        }
+        // The rows are keyed, so the state changed by the user stays with the node, when the
+        // children are loaded
+        item.root_tag_mut().key = Some(Key::from(key.as_str()));
+        rows.push(item.into());
+
+        let size = children.len();
+        for (idx, child) in children.into_iter().enumerate() {
+            let position = Position {
+                level: position.level + 1,
+                index: idx + 1,
+                size,
+            };
+            child.render(position, Some(&key), is_hidden || !expanded, rows);
+        }
+    }
+}
+
+/// The position of the node in the tree, as it is announced by the ARIA attributes.
+#[derive(Clone, Copy)]
+struct Position {
+    level: usize,
+    index: usize,
+    size: usize,
+}
+
+impl Position {
+    fn is_first(&self) -> bool {
+        self.level == 1 && self.index == 1
+    }
+}
+
+/// The tree of the expandable nodes, which is composed from the list items.
+///
+/// The nodes are expanded and selected by click, and by the keyboard like the ARIA tree: the Up
+/// and Down arrows move the focus, the Right arrow expands the node or moves to its first child,
+/// the Left arrow collapses the node or moves to its parent, Home and End move to the first and
+/// last nodes, `*` expands all the siblings, Enter and Space select the node.
+#[derive(Debug, Clone)]
+pub struct TreeView {
+    html: Html,
+    nodes: Vec<TreeNode>,
+}
+
+impl Default for TreeView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TreeView {
+    pub const CLASS: &'static str = "yew-mdc-tree-view";
+    pub const TOGGLE_CLASS: &'static str = "yew-mdc-tree-view__toggle";
+    pub const LEVEL_VAR: &'static str = "--yew-mdc-tree-view-level";
+    pub const KEY_ATTR: &'static str = "data-node-key";
+    pub const PARENT_ATTR: &'static str = "data-parent-key";
+    pub const LAZY_ATTR: &'static str = "data-lazy";
+
+    pub const TOGGLE_EVENT: &'static str = "TreeView:toggle";
+    pub const SELECT_EVENT: &'static str = "TreeView:select";
+    pub const LOAD_EVENT: &'static str = "TreeView:load";
+
+    pub fn new() -> Self {
+        let tree = Self {
+            html: html! { <ul class = { classes!(List::CLASS, Self::CLASS) } role = "tree"></ul> },
+            nodes: Vec::new(),
+        };
+        tree.listener(Rc::new(onclick::Wrapper::new(Callback::from(on_click))))
+            .listener(Rc::new(onkeydown::Wrapper::new(Callback::from(on_key_down))))
+    }
+
+    pub fn node(mut self, node: TreeNode) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    pub fn nodes(mut self, nodes: impl IntoIterator<Item = TreeNode>) -> Self {
+        self.nodes.extend(nodes);
+        self
+    }
+
+    /// Allows selecting several nodes, each node selection is toggled by the user.
+    pub fn multi_selection(self) -> Self {
+        self.attr("aria-multiselectable", "true")
+    }
+
+    /// Emits when the node is expanded or collapsed by the user.
+    pub fn on_toggle(self, callback: impl Into<Callback<TreeToggle>>) -> Self {
+        let callback = callback.into();
+        self.on_event(Self::TOGGLE_EVENT, move |event: CustomEvent| {
+            let detail = event.detail();
+            if let Some(key) = detail.get("key").as_string() {
+                callback.emit(TreeToggle {
+                    key,
+                    expanded: detail.get("expanded").is_truthy(),
+                });
+            }
+        })
+    }
+
+    /// Emits when the node is selected or unselected by the user.
+    pub fn on_select(self, callback: impl Into<Callback<TreeSelection>>) -> Self {
+        let callback = callback.into();
+        self.on_event(Self::SELECT_EVENT, move |event: CustomEvent| {
+            let detail = event.detail();
+            if let Some(key) = detail.get("key").as_string() {
+                callback.emit(TreeSelection {
+                    key,
+                    selected: detail.get("selected").is_truthy(),
+                });
+            }
+        })
+    }
+
+    /// Emits the key of the [`lazy`](TreeNode::lazy) node on its first expansion. The node stays
+    /// expanded, so the children are shown as soon as they are rendered.
+    pub fn on_load(self, callback: impl Into<Callback<String>>) -> Self {
+        let callback = callback.into();
+        self.on_event(Self::LOAD_EVENT, move |event: CustomEvent| {
+            if let Some(key) = event.detail().get("key").as_string() {
+                callback.emit(key);
+            }
+        })
+    }
+
+    pub fn expand_existing(id: impl AsRef<str>, key: impl AsRef<str>) {
+        if let Some((tree, node)) = Self::existing_node(id.as_ref(), key.as_ref()) {
+            set_expanded(&tree, &node, true);
+        }
+    }
+
+    pub fn collapse_existing(id: impl AsRef<str>, key: impl AsRef<str>) {
+        if let Some((tree, node)) = Self::existing_node(id.as_ref(), key.as_ref()) {
+            set_expanded(&tree, &node, false);
+        }
+    }
+
+    /// Returns the keys of the selected nodes of the existing tree.
+    pub fn selected_keys_existing(id: impl AsRef<str>) -> Vec<String> {
+        let tree = dom::existing::get_element_by_id::<Element>(id.as_ref());
+        tree_nodes(&tree)
+            .into_iter()
+            .filter(is_selected)
+            .filter_map(|node| node.get_attribute(Self::KEY_ATTR))
+            .collect()
+    }
+
+    fn existing_node(id: &str, key: &str) -> Option<(Element, Element)> {
+        let tree = dom::existing::get_element_by_id::<Element>(id);
+        let node = find_node(&tree, key);
+        if node.is_none() {
+            console::error!(format!("The {} '{}' has no node with key '{}'", Self::NAME, id, key));
+        }
+        node.map(|node| (tree, node))
+    }
+}
+
+fn on_click(event: MouseEvent) {
+    let (Some(tree), Some(node)) = (event_tree(&event), event_node(&event)) else {
+        return;
+    };
+    let is_toggle = event_target(&event)
+        .and_then(|target| target.closest(concatcp!(".", TreeView::TOGGLE_CLASS)).ok().flatten())
+        .is_some();
+    if is_toggle {
+        set_expanded(&tree, &node, !is_expanded(&node));
+    } else {
+        select(&tree, &node);
+    }
+    focus(&tree, &node);
+}
+
+fn on_key_down(event: KeyboardEvent) {
+    let (Some(tree), Some(node)) = (event_tree(&event), event_node(&event)) else {
+        return;
+    };
+    let visible = tree_nodes(&tree)
+        .into_iter()
+        .filter(|node| !node.has_attribute("hidden"))
+        .collect::<Vec<_>>();
+    let Some(idx) = visible.iter().position(|visible| visible == &node) else {
+        return;
+    };
+
+    let mut next = None;
+    match event.key().as_str() {
+        "ArrowDown" => next = visible.get(idx + 1).cloned(),
+        "ArrowUp" => next = idx.checked_sub(1).and_then(|idx| visible.get(idx).cloned()),
+        "Home" => next = visible.first().cloned(),
+        "End" => next = visible.last().cloned(),
+        "ArrowRight" => match expanded_state(&node) {
+            Some(false) => set_expanded(&tree, &node, true),
+            Some(true) => {
+                next = visible
+                    .get(idx + 1)
+                    .filter(|child| level(child) > level(&node))
+                    .cloned()
+            },
+            None => (),
+        },
+        "ArrowLeft" => match expanded_state(&node) {
+            Some(true) => set_expanded(&tree, &node, false),
+            _ => {
+                next = node
+                    .get_attribute(TreeView::PARENT_ATTR)
+                    .and_then(|parent| find_node(&tree, &parent))
+            },
+        },
+        "*" => {
+            let parent = node.get_attribute(TreeView::PARENT_ATTR);
+            for sibling in tree_nodes(&tree) {
+                if level(&sibling) == level(&node) && sibling.get_attribute(TreeView::PARENT_ATTR) == parent {
+                    set_expanded(&tree, &sibling, true);
+                }
+            }
+        },
+        "Enter" | " " => select(&tree, &node),
+        _ => return,
+    }
+    event.prevent_default();
+    if let Some(next) = next {
+        focus(&tree, &next);
+    }
+}
+
+fn set_expanded(tree: &Element, node: &Element, expanded: bool) {
+    if expanded_state(node).is_none_or(|state| state == expanded) {
+        return;
+    }
+    let _ = node.set_attribute("aria-expanded", &expanded.to_string());
+    sync(tree);
+
+    let key = node.get_attribute(TreeView::KEY_ATTR).unwrap_or_default();
+    if expanded && node.has_attribute(TreeView::LAZY_ATTR) && !node.has_attribute("aria-busy") {
+        let _ = node.set_attribute("aria-busy", "true");
+        emit_custom_event(tree, TreeView::LOAD_EVENT, &[("key", JsValue::from_str(&key))]);
+    }
+    emit_custom_event(tree, TreeView::TOGGLE_EVENT, &[
+        ("key", JsValue::from_str(&key)),
+        ("expanded", JsValue::from(expanded)),
+    ]);
+}
+
+fn select(tree: &Element, node: &Element) {
+    let is_multi = tree.get_attribute("aria-multiselectable").as_deref() == Some("true");
+    let selected = !(is_multi && is_selected(node));
+    if !is_multi {
+        if is_selected(node) {
+            return;
+        }
+        for other in tree_nodes(tree).iter().filter(|other| is_selected(other)) {
+            set_selected(other, false);
+        }
+    }
+    set_selected(node, selected);
+
+    let key = node.get_attribute(TreeView::KEY_ATTR).unwrap_or_default();
+    emit_custom_event(tree, TreeView::SELECT_EVENT, &[
+        ("key", JsValue::from_str(&key)),
+        ("selected", JsValue::from(selected)),
+    ]);
+}
+
+fn set_selected(node: &Element, selected: bool) {
+    let _ = node.set_attribute("aria-selected", &selected.to_string());
+    let _ = node.class_list().toggle_with_force(ListItem::SELECTION_CLASS, selected);
+}
+
+/// Moves the single tab stop of the tree to the node and focuses it.
+fn focus(tree: &Element, node: &Element) {
+    for other in tree_nodes(tree) {
+        let _ = other.set_attribute("tabindex", if &other == node { "0" } else { "-1" });
+    }
+    if let Some(node) = node.dyn_ref::<HtmlElement>() {
+        let _ = node.focus();
+    }
+}
+
+/// Hides the descendants of the collapsed nodes, and keeps the tab stop on a visible node. The
+/// nodes are in the document order, so the descendants follow the node with the greater levels.
+fn sync(tree: &Element) {
+    let nodes = tree_nodes(tree);
+    let mut collapsed_level = None;
+    let mut lost_tab_stop = false;
+    for node in &nodes {
+        let node_level = level(node);
+        if collapsed_level.is_some_and(|collapsed| node_level > collapsed) {
+            if node.get_attribute("tabindex").as_deref() == Some("0") {
+                let _ = node.set_attribute("tabindex", "-1");
+                lost_tab_stop = true;
+            }
+            let _ = node.set_attribute("hidden", "hidden");
+            continue;
+        }
+        let _ = node.remove_attribute("hidden");
+        collapsed_level = (expanded_state(node) == Some(false)).then_some(node_level);
+
+        // The lazy mark is rendered until the children are loaded, or the load finds no children
+        if !node.has_attribute(TreeView::LAZY_ATTR) {
+            let _ = node.remove_attribute("aria-busy");
+        }
+    }
+
+    let has_tab_stop = nodes
+        .iter()
+        .any(|node| node.get_attribute("tabindex").as_deref() == Some("0"));
+    if lost_tab_stop || !has_tab_stop {
+        let visible = nodes.iter().filter(|node| !node.has_attribute("hidden"));
+        let tab_stop = visible
+            .clone()
+            .find(|node| is_selected(node))
+            .or_else(|| visible.clone().next());
+        if let Some(tab_stop) = tab_stop {
+            let _ = tab_stop.set_attribute("tabindex", "0");
+        }
+    }
+}
+
+fn tree_nodes(tree: &Element) -> Vec<Element> {
+    let Ok(nodes) = tree.query_selector_all(concatcp!("[", TreeView::KEY_ATTR, "]")) else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|idx| nodes.get(idx))
+        .filter_map(|node| node.dyn_into::<Element>().ok())
+        .collect()
+}
+
+fn find_node(tree: &Element, key: &str) -> Option<Element> {
+    tree_nodes(tree)
+        .into_iter()
+        .find(|node| node.get_attribute(TreeView::KEY_ATTR).as_deref() == Some(key))
+}
+
+fn level(node: &Element) -> usize {
+    node.get_attribute("aria-level")
+        .and_then(|level| level.parse().ok())
+        .unwrap_or(1)
+}
+
+/// Returns the expanded state of the node, or `None` for the leaf node.
+fn expanded_state(node: &Element) -> Option<bool> {
+    node.get_attribute("aria-expanded").map(|expanded| expanded == "true")
+}
+
+fn is_expanded(node: &Element) -> bool {
+    expanded_state(node) == Some(true)
+}
+
+fn is_selected(node: &Element) -> bool {
+    node.get_attribute("aria-selected").as_deref() == Some("true")
+}
+
+fn event_target(event: &Event) -> Option<Element> {
+    event.target().and_then(|target| target.dyn_into::<Element>().ok())
+}
+
+fn event_tree(event: &Event) -> Option<Element> {
+    event_target(event)?
+        .closest(concatcp!(".", TreeView::CLASS))
+        .ok()
+        .flatten()
+}
+
+fn event_node(event: &Event) -> Option<Element> {
+    event_target(event)?
+        .closest(concatcp!("[", TreeView::KEY_ATTR, "]"))
+        .ok()
+        .flatten()
+}
+
+impl MdcWidget for TreeView {
+    const NAME: &'static str = stringify!(TreeView);
+
+    fn html(&self) -> &Html {
+        &self.html
+    }
+
+    fn html_mut(&mut self) -> &mut Html {
+        &mut self.html
+    }
+}
+
+impl Deref for TreeView {
+    type Target = Html;
+
+    fn deref(&self) -> &Self::Target {
+        &self.html
+    }
+}
+
+impl DerefMut for TreeView {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.html
+    }
+}
+
+impl From<TreeView> for Html {
+    fn from(tree: TreeView) -> Self {
+        let TreeView { mut html, nodes } = tree;
+        let mut rows = Vec::new();
+        let size = nodes.len();
+        for (idx, node) in nodes.into_iter().enumerate() {
+            let position = Position {
+                level: 1,
+                index: idx + 1,
+                size,
+            };
+            node.render(position, None, false, &mut rows);
+        }
+
+        if let Some(root) = html.root_tag_mut() {
+            for row in rows {
+                root.add_child(row);
+            }
+            // The rendered nodes follow the expanded state, which is changed by the user
+            push_after_render(root, sync);
+        }
+        html
+    }
+}
+
+impl ToHtml for TreeView {
+    fn to_html(&self) -> Html {
+        self.clone().into()
+    }
+
+    fn into_html(self) -> Html {
+        self.into()
+    }
+}
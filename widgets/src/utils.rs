@@ -1,7 +1,9 @@
 use std::rc::Rc;
 
+use wasm_bindgen::JsValue;
+use wasm_dom::existing::JsObjectAccess;
 use wasm_dom::UnwrapThrowExt;
-use web_sys::Node;
+use web_sys::{CustomEvent, CustomEventInit, Element, Node};
 use yew::html::onclick;
 use yew::{html, Callback, Classes, Html, MouseEvent};
 
@@ -19,6 +21,27 @@ pub fn raw_html(tag: impl AsRef<str>, inner_html: impl AsRef<str>) -> Html {
     Html::VRef(Node::from(html))
 }
 
+/// Dispatches the bubbling custom event with the detail object, which has the fields.
+pub(crate) fn emit_custom_event(target: &Element, event_type: &str, detail: &[(&str, JsValue)]) {
+    let object = js_sys::Object::new();
+    for (name, value) in detail {
+        let _ = js_sys::Reflect::set(&object, &JsValue::from_str(name), value);
+    }
+
+    let init = CustomEventInit::new();
+    init.set_bubbles(true);
+    init.set_detail(&object);
+    if let Ok(event) = CustomEvent::new_with_event_init_dict(event_type, &init) {
+        let _ = target.dispatch_event(&event);
+    }
+}
+
+/// Returns the MDC object of the element if the element is already initialized.
+pub(crate) fn mdc_object(element: &Element, type_name: &str) -> Option<JsValue> {
+    let object = element.get(type_name);
+    (!object.is_undefined()).then_some(object)
+}
+
 pub(crate) trait IntoWidgetWithVList: MdcWidget {
     fn into_widget_with_v_list(self) -> Self;
 }
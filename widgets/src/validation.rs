@@ -0,0 +1,143 @@
+use std::fmt;
+use std::rc::Rc;
+
+use wasm_bindgen::prelude::*;
+
+use crate::console;
+use crate::js_sys::RegExp;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Creates the regular expression, throws `SyntaxError` on the invalid pattern.
+    #[wasm_bindgen(catch, js_name = RegExp)]
+    fn try_reg_exp(pattern: &str, flags: &str) -> Result<RegExp, JsValue>;
+}
+
+pub type ValidateFn = dyn Fn(&str) -> Result<(), String>;
+
+/// Checks a value of a form control, returns the error message if the value is invalid.
+#[derive(Clone)]
+pub struct Validator {
+    validate: Rc<ValidateFn>,
+}
+
+impl Validator {
+    pub fn new(validate: impl Fn(&str) -> Result<(), String> + 'static) -> Self {
+        Self {
+            validate: Rc::new(validate),
+        }
+    }
+
+    /// Fails on the empty or whitespace-only value.
+    pub fn required() -> Self {
+        Self::new(|value| {
+            if value.trim().is_empty() {
+                Err("This field is required".into())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Fails on the non-empty value shorter than `min` characters.
+    pub fn min_length(min: usize) -> Self {
+        Self::new(move |value| {
+            if !value.is_empty() && value.chars().count() < min {
+                Err(format!("Must be at least {} characters", min))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Fails on the value longer than `max` characters.
+    pub fn max_length(max: usize) -> Self {
+        Self::new(move |value| {
+            if value.chars().count() > max {
+                Err(format!("Must be at most {} characters", max))
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Fails on the non-empty value which does not entirely match the JavaScript regular
+    /// expression `pattern`, like the `pattern` attribute of the input does. The invalid pattern
+    /// is logged as an error, and the validator passes any value then.
+    pub fn pattern(pattern: impl AsRef<str>) -> Self {
+        let regexp = match try_reg_exp(&format!("^(?:{})$", pattern.as_ref()), "u") {
+            Ok(regexp) => regexp,
+            Err(err) => {
+                console::error!(format!("Invalid validator pattern `{}`:", pattern.as_ref()), err);
+                return Self::new(|_| Ok(()));
+            },
+        };
+        Self::new(move |value| {
+            if !value.is_empty() && !regexp.test(value) {
+                Err("Invalid format".into())
+            } else {
+                Ok(())
+            }
+        })
+    }
+
+    /// Fails on the non-empty value which is not an email address.
+    pub fn email() -> Self {
+        Self::pattern(r"[^\s@]+@[^\s@]+\.[^\s@]+").message("Invalid email address")
+    }
+
+    /// Fails on the non-empty value which is not a number in the `min..=max` range.
+    pub fn range(min: f64, max: f64) -> Self {
+        Self::new(move |value| {
+            let value = value.trim();
+            if value.is_empty() {
+                return Ok(());
+            }
+            match value.parse::<f64>() {
+                Ok(number) if (min..=max).contains(&number) => Ok(()),
+                Ok(_) => Err(format!("Must be between {} and {}", min, max)),
+                Err(_) => Err("Must be a number".into()),
+            }
+        })
+    }
+
+    /// Replaces the error message of the validator.
+    pub fn message(self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        Self::new(move |value| self.validate(value).map_err(|_| message.clone()))
+    }
+
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        (self.validate)(value)
+    }
+
+    /// Runs the validators in order and returns the first error.
+    pub fn validate_all<'a>(validators: impl IntoIterator<Item = &'a Validator>, value: &str) -> Result<(), String> {
+        validators
+            .into_iter()
+            .try_for_each(|validator| validator.validate(value))
+    }
+}
+
+impl<F> From<F> for Validator
+where
+    F: Fn(&str) -> Result<(), String> + 'static,
+{
+    fn from(validate: F) -> Self {
+        Self::new(validate)
+    }
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Validator)).finish_non_exhaustive()
+    }
+}
+
+/// When the validators of a control are run.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum ValidateOn {
+    Input,
+    #[default]
+    Blur,
+}
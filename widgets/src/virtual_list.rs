@@ -0,0 +1,610 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+
+use yew::virtual_dom::{AttrValue, Key};
+use yew::{
+    function_component, html, use_effect, use_force_update, use_mut_ref, use_node_ref, use_state, Callback, Event,
+    Html, KeyboardEvent, MouseEvent, NodeRef, Properties, ToHtml, UseStateHandle,
+};
+
+use crate::dom::JsCast;
+use crate::utils::VTagExt;
+use crate::web_sys::HtmlElement;
+use crate::{js_sys, Element, ListItem, MdcWidget};
+
+pub type RenderItemFn = dyn Fn(usize) -> Html;
+
+pub type ItemTextFn = dyn Fn(usize) -> String;
+
+/// The height of the virtual list items.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ItemHeight {
+    /// All the items have the same height in pixels.
+    Fixed(f64),
+    /// The items have the estimated height in pixels, until they are rendered and measured.
+    Estimated(f64),
+}
+
+impl ItemHeight {
+    fn estimate(&self) -> f64 {
+        match self {
+            Self::Fixed(height) | Self::Estimated(height) => *height,
+        }
+    }
+}
+
+/// The list, which renders only the items visible in the scrolled viewport, see
+/// [`List::virtualized`](crate::List::virtualized).
+///
+/// The focus is moved by the arrow, Page Up/Down and Home/End keys across all the items, not only
+/// the rendered ones, and the item is activated by click or by the Enter or Space keys. The items
+/// are not upgraded to `MDCList`, as it knows only the rendered items.
+#[derive(Clone)]
+pub struct VirtualList {
+    html: Html,
+    item_count: usize,
+    item_height: ItemHeight,
+    viewport_height: AttrValue,
+    overscan: usize,
+    end_threshold: usize,
+    render_item: Rc<RenderItemFn>,
+    item_text: Option<Rc<ItemTextFn>>,
+    on_action: Option<Callback<usize>>,
+    on_end_reached: Option<Callback<()>>,
+}
+
+impl VirtualList {
+    pub const VIEWPORT_CLASS: &'static str = "yew-mdc-virtual-list";
+    pub const INDEX_ATTR: &'static str = "data-index";
+
+    const TYPEAHEAD_TIMEOUT_MS: f64 = 500.0;
+
+    pub(crate) fn new(html: Html, item_count: usize, item_height: ItemHeight, render_item: Rc<RenderItemFn>) -> Self {
+        Self {
+            html,
+            item_count,
+            item_height,
+            viewport_height: AttrValue::from("400px"),
+            overscan: 5,
+            end_threshold: 10,
+            render_item,
+            item_text: None,
+            on_action: None,
+            on_end_reached: None,
+        }
+    }
+
+    /// Sets the CSS height of the scrolled viewport, `400px` by default.
+    pub fn viewport_height(mut self, height: impl Into<AttrValue>) -> Self {
+        self.viewport_height = height.into();
+        self
+    }
+
+    /// Sets the number of the items rendered above and below the viewport, `5` by default.
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Enables the typeahead: the focus is moved to the next item, which text starts with the typed
+    /// characters.
+    pub fn typeahead(mut self, item_text: impl Fn(usize) -> String + 'static) -> Self {
+        self.item_text = Some(Rc::new(item_text));
+        self
+    }
+
+    /// Emits the index of the item, when the item is activated by a click or by the Enter or
+    /// Space keys.
+    pub fn on_action(mut self, callback: impl Into<Callback<usize>>) -> Self {
+        self.on_action = Some(callback.into());
+        self
+    }
+
+    /// Emits once per item count, when the rendered items come closer to the end of the list than
+    /// the threshold. Load more items and increase the item count to continue the scrolling.
+    pub fn on_end_reached(mut self, callback: impl Into<Callback<()>>) -> Self {
+        self.on_end_reached = Some(callback.into());
+        self
+    }
+
+    /// Sets the number of the items before the end of the list, which triggers the
+    /// [`on_end_reached`](Self::on_end_reached) callback, `10` by default.
+    pub fn end_threshold(mut self, threshold: usize) -> Self {
+        self.end_threshold = threshold;
+        self
+    }
+
+    fn layout<'a>(&self, measured: &'a MeasuredHeights) -> Layout<'a> {
+        Layout {
+            count: self.item_count,
+            estimate: self.item_height.estimate(),
+            measured,
+        }
+    }
+}
+
+impl PartialEq for VirtualList {
+    fn eq(&self, other: &Self) -> bool {
+        self.html == other.html
+            && self.item_count == other.item_count
+            && self.item_height == other.item_height
+            && self.viewport_height == other.viewport_height
+            && self.overscan == other.overscan
+            && self.end_threshold == other.end_threshold
+            && Rc::ptr_eq(&self.render_item, &other.render_item)
+            && match (&self.item_text, &other.item_text) {
+                (Some(this), Some(other)) => Rc::ptr_eq(this, other),
+                (this, other) => this.is_none() && other.is_none(),
+            }
+            && self.on_action == other.on_action
+            && self.on_end_reached == other.on_end_reached
+    }
+}
+
+/// The measured heights of the rendered items. The heights and the counts of the measured items are
+/// kept in the Fenwick tree as well, so the item offsets are computed in logarithmic time.
+#[derive(Default)]
+struct MeasuredHeights {
+    heights: BTreeMap<usize, f64>,
+    tree: Vec<(f64, usize)>,
+}
+
+impl MeasuredHeights {
+    fn get(&self, idx: usize) -> Option<f64> {
+        self.heights.get(&idx).copied()
+    }
+
+    /// Stores the measured height of the item, returns whether the height is changed.
+    fn insert(&mut self, idx: usize, height: f64) -> bool {
+        let previous = self.heights.insert(idx, height);
+        if idx < self.tree.len() {
+            let added_count = if previous.is_none() { 1 } else { 0 };
+            self.add(idx, height - previous.unwrap_or(0.0), added_count);
+        } else {
+            self.rebuild((idx + 1).next_power_of_two());
+        }
+        previous.is_none_or(|previous| (previous - height).abs() > 0.5)
+    }
+
+    /// Returns the sum of the heights and the count of the measured items before the index.
+    fn prefix(&self, idx: usize) -> (f64, usize) {
+        let (mut sum, mut count) = (0.0, 0);
+        let mut position = idx.min(self.tree.len());
+        while position > 0 {
+            let (height, measured) = self.tree[position - 1];
+            sum += height;
+            count += measured;
+            position &= position - 1;
+        }
+        (sum, count)
+    }
+
+    fn add(&mut self, idx: usize, height: f64, count: usize) {
+        let mut position = idx + 1;
+        while position <= self.tree.len() {
+            let node = &mut self.tree[position - 1];
+            node.0 += height;
+            node.1 += count;
+            position += position & position.wrapping_neg();
+        }
+    }
+
+    fn rebuild(&mut self, len: usize) {
+        self.tree = vec![(0.0, 0); len];
+        let heights: Vec<_> = self.heights.iter().map(|(idx, height)| (*idx, *height)).collect();
+        for (idx, height) in heights {
+            self.add(idx, height, 1);
+        }
+    }
+}
+
+/// The item offsets, which take the measured heights of the rendered items into account.
+struct Layout<'a> {
+    count: usize,
+    estimate: f64,
+    measured: &'a MeasuredHeights,
+}
+
+impl Layout<'_> {
+    fn height_of(&self, idx: usize) -> f64 {
+        self.measured.get(idx).unwrap_or(self.estimate)
+    }
+
+    fn offset_of(&self, idx: usize) -> f64 {
+        let (measured_sum, measured_count) = self.measured.prefix(idx);
+        (idx - measured_count) as f64 * self.estimate + measured_sum
+    }
+
+    fn total(&self) -> f64 {
+        self.offset_of(self.count)
+    }
+
+    /// Returns the index of the item at the offset.
+    fn index_at(&self, offset: f64) -> usize {
+        let (mut low, mut high) = (0, self.count);
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            if self.offset_of(middle) <= offset {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+}
+
+#[derive(Default)]
+struct ViewState {
+    measured: MeasuredHeights,
+    pending_focus: Option<usize>,
+    end_reached_count: Option<usize>,
+    typeahead: String,
+    typeahead_time: f64,
+}
+
+#[derive(Properties, PartialEq)]
+struct ViewProps {
+    list: VirtualList,
+}
+
+#[function_component(VirtualListView)]
+fn virtual_list_view(props: &ViewProps) -> Html {
+    let list = &props.list;
+    let viewport_ref = use_node_ref();
+    let scroll_top = use_state(|| 0.0_f64);
+    let viewport_size = use_state(|| 0.0_f64);
+    let focused = use_state(|| 0_usize);
+    let state = use_mut_ref(ViewState::default);
+    let force_update = use_force_update();
+
+    let (start, end, padding_top, padding_bottom) = {
+        let state = state.borrow();
+        let layout = list.layout(&state.measured);
+        if list.item_count == 0 {
+            (0, 0, 0.0, 0.0)
+        } else {
+            let start = layout.index_at(*scroll_top).saturating_sub(list.overscan);
+            let end = (layout.index_at(*scroll_top + *viewport_size) + 1 + list.overscan).min(list.item_count);
+            let padding_top = layout.offset_of(start);
+            (start, end, padding_top, layout.total() - layout.offset_of(end))
+        }
+    };
+    let focused_idx = (*focused).min(list.item_count.saturating_sub(1));
+    let tab_stop = if (start..end).contains(&focused_idx) {
+        focused_idx
+    } else {
+        start
+    };
+
+    {
+        let list = list.clone();
+        let viewport_ref = viewport_ref.clone();
+        let viewport_size = viewport_size.clone();
+        let state = state.clone();
+        use_effect(move || {
+            let Some(viewport) = viewport_ref.cast::<Element>() else {
+                return;
+            };
+            let size = viewport.client_height() as f64;
+            if size != *viewport_size {
+                viewport_size.set(size);
+            }
+
+            let mut state = state.borrow_mut();
+            if matches!(list.item_height, ItemHeight::Estimated(_)) {
+                let mut is_changed = false;
+                for item in rendered_items(&viewport) {
+                    if let Some(idx) = item_index(&item) {
+                        let height = item.get_bounding_client_rect().height();
+                        is_changed |= state.measured.insert(idx, height);
+                    }
+                }
+                if is_changed {
+                    force_update.force_update();
+                }
+            }
+
+            if let Some(idx) = state.pending_focus.take() {
+                let item = rendered_items(&viewport)
+                    .into_iter()
+                    .find(|item| item_index(item) == Some(idx));
+                if let Some(item) = item.and_then(|item| item.dyn_into::<HtmlElement>().ok()) {
+                    let _ = item.focus();
+                }
+            }
+
+            if let Some(on_end_reached) = &list.on_end_reached {
+                let is_near_end = end + list.end_threshold >= list.item_count;
+                if is_near_end && state.end_reached_count != Some(list.item_count) {
+                    state.end_reached_count = Some(list.item_count);
+                    on_end_reached.emit(());
+                }
+            }
+        });
+    }
+
+    let move_focus = {
+        let list = list.clone();
+        let viewport_ref = viewport_ref.clone();
+        let scroll_top = scroll_top.clone();
+        let viewport_size = viewport_size.clone();
+        let focused = focused.clone();
+        let state = state.clone();
+        move |idx: usize| {
+            let mut state = state.borrow_mut();
+            let top = {
+                let layout = list.layout(&state.measured);
+                scroll_top_to_show(&layout, idx, *scroll_top, *viewport_size)
+            };
+            if top != *scroll_top {
+                scroll_to(&viewport_ref, &scroll_top, top);
+            }
+            state.pending_focus = Some(idx);
+            focused.set(idx);
+        }
+    };
+
+    let on_keydown = {
+        let list = list.clone();
+        let viewport_size = viewport_size.clone();
+        let state = state.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if list.item_count == 0 {
+                return;
+            }
+            let last = list.item_count - 1;
+            let page = ((*viewport_size / list.item_height.estimate()) as usize).max(1);
+            let next = match event.key().as_str() {
+                "ArrowDown" => Some((focused_idx + 1).min(last)),
+                "ArrowUp" => Some(focused_idx.saturating_sub(1)),
+                "PageDown" => Some((focused_idx + page).min(last)),
+                "PageUp" => Some(focused_idx.saturating_sub(page)),
+                "Home" => Some(0),
+                "End" => Some(last),
+                "Enter" | " " => {
+                    event.prevent_default();
+                    if let Some(on_action) = &list.on_action {
+                        on_action.emit(focused_idx);
+                    }
+                    None
+                },
+                key if key.chars().count() == 1 && !(event.ctrl_key() || event.alt_key() || event.meta_key()) => {
+                    let item_text = list.item_text.as_ref();
+                    item_text.and_then(|item_text| typeahead(&state, item_text, list.item_count, focused_idx, key))
+                },
+                _ => None,
+            };
+            if let Some(next) = next {
+                event.prevent_default();
+                move_focus(next);
+            }
+        })
+    };
+
+    let on_click = {
+        let on_action = list.on_action.clone();
+        let focused = focused.clone();
+        Callback::from(move |event: MouseEvent| {
+            let item = event
+                .target()
+                .and_then(|target| target.dyn_into::<Element>().ok())
+                .and_then(|target| target.closest(&format!("[{}]", VirtualList::INDEX_ATTR)).ok().flatten());
+            if let Some(idx) = item.as_ref().and_then(item_index) {
+                focused.set(idx);
+                if let Some(on_action) = &on_action {
+                    on_action.emit(idx);
+                }
+            }
+        })
+    };
+
+    let on_scroll = {
+        let viewport_ref = viewport_ref.clone();
+        let scroll_top = scroll_top.clone();
+        Callback::from(move |_: Event| {
+            if let Some(viewport) = viewport_ref.cast::<Element>() {
+                scroll_top.set(viewport.scroll_top() as f64);
+            }
+        })
+    };
+
+    let mut shell = list.html.clone();
+    if let Some(root) = shell.root_tag_mut() {
+        // The paddings go last, so the own style of the list does not break the scrolled height
+        let paddings = format!("padding-top: {}px; padding-bottom: {}px;", padding_top, padding_bottom);
+        let style = match root.attr("style") {
+            Some(own) if !own.trim().is_empty() => format!("{}; {}", own.trim().trim_end_matches(';'), paddings),
+            _ => paddings,
+        };
+        root.set_attr("style", style);
+        for idx in start..end {
+            let mut item = (list.render_item)(idx);
+            if let Some(tag) = item.root_tag_mut() {
+                tag.key = Some(Key::from(idx));
+                if tag.attr("role").is_none() {
+                    tag.set_attr("role", "option");
+                }
+                tag.set_attr(VirtualList::INDEX_ATTR, idx.to_string());
+                tag.set_attr("aria-posinset", (idx + 1).to_string());
+                tag.set_attr("aria-setsize", list.item_count.to_string());
+                tag.set_attr("tabindex", if idx == tab_stop { "0" } else { "-1" });
+            }
+            root.add_child(item);
+        }
+    }
+
+    html! {
+        <div ref = { viewport_ref } class = { VirtualList::VIEWPORT_CLASS }
+                style = { format!("height: {};", list.viewport_height) }
+                onscroll = { on_scroll } onkeydown = { on_keydown } onclick = { on_click }>
+            { shell }
+        </div>
+    }
+}
+
+/// Returns the scroll position, which shows the whole item in the viewport.
+fn scroll_top_to_show(layout: &Layout, idx: usize, scroll_top: f64, viewport_size: f64) -> f64 {
+    let top = layout.offset_of(idx);
+    let bottom = top + layout.height_of(idx);
+    if top < scroll_top {
+        top
+    } else if bottom > scroll_top + viewport_size {
+        bottom - viewport_size
+    } else {
+        scroll_top
+    }
+}
+
+fn scroll_to(viewport_ref: &NodeRef, scroll_top: &UseStateHandle<f64>, top: f64) {
+    if let Some(viewport) = viewport_ref.cast::<Element>() {
+        viewport.set_scroll_top(top as i32);
+    }
+    scroll_top.set(top);
+}
+
+/// Returns the index of the next item, which text starts with the typed characters.
+fn typeahead(
+    state: &RefCell<ViewState>,
+    item_text: &Rc<ItemTextFn>,
+    count: usize,
+    focused: usize,
+    key: &str,
+) -> Option<usize> {
+    let mut state = state.borrow_mut();
+    let now = js_sys::Date::now();
+    if now - state.typeahead_time > VirtualList::TYPEAHEAD_TIMEOUT_MS {
+        state.typeahead.clear();
+    }
+    state.typeahead_time = now;
+    state.typeahead.push_str(&key.to_lowercase());
+
+    // The single character moves the focus to the next matching item, the prefix can match the focused one
+    let start = if state.typeahead.chars().count() == 1 {
+        focused + 1
+    } else {
+        focused
+    };
+    (0..count)
+        .map(|offset| (start + offset) % count)
+        .find(|&idx| item_text(idx).to_lowercase().starts_with(&state.typeahead))
+}
+
+fn rendered_items(viewport: &Element) -> Vec<Element> {
+    let Ok(items) = viewport.query_selector_all(&format!(".{}[{}]", ListItem::CLASS, VirtualList::INDEX_ATTR)) else {
+        return Vec::new();
+    };
+    (0..items.length())
+        .filter_map(|idx| items.get(idx))
+        .filter_map(|item| item.dyn_into::<Element>().ok())
+        .collect()
+}
+
+fn item_index(item: &Element) -> Option<usize> {
+    item.get_attribute(VirtualList::INDEX_ATTR)?.parse().ok()
+}
+
+impl fmt::Debug for VirtualList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualList")
+            .field("html", &self.html)
+            .field("item_count", &self.item_count)
+            .field("item_height", &self.item_height)
+            .field("viewport_height", &self.viewport_height)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MdcWidget for VirtualList {
+    const NAME: &'static str = stringify!(VirtualList);
+
+    fn html(&self) -> &Html {
+        &self.html
+    }
+
+    fn html_mut(&mut self) -> &mut Html {
+        &mut self.html
+    }
+}
+
+impl Deref for VirtualList {
+    type Target = Html;
+
+    fn deref(&self) -> &Self::Target {
+        &self.html
+    }
+}
+
+impl DerefMut for VirtualList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.html
+    }
+}
+
+impl From<VirtualList> for Html {
+    fn from(list: VirtualList) -> Self {
+        html! { <VirtualListView list = { list } /> }
+    }
+}
+
+impl ToHtml for VirtualList {
+    fn to_html(&self) -> Html {
+        self.clone().into()
+    }
+
+    fn into_html(self) -> Html {
+        self.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_offset(heights: &BTreeMap<usize, f64>, estimate: f64, idx: usize) -> f64 {
+        (0..idx).map(|idx| heights.get(&idx).copied().unwrap_or(estimate)).sum()
+    }
+
+    #[test]
+    fn offsets_take_measured_heights_into_account() {
+        let mut measured = MeasuredHeights::default();
+        for (idx, height) in [(3, 60.0), (0, 20.0), (17, 100.0), (3, 40.0), (64, 10.0)] {
+            measured.insert(idx, height);
+        }
+        let layout = Layout {
+            count: 100,
+            estimate: 48.0,
+            measured: &measured,
+        };
+        for idx in 0..=100 {
+            assert_eq!(
+                layout.offset_of(idx),
+                naive_offset(&measured.heights, 48.0, idx),
+                "offset of {idx}"
+            );
+        }
+        assert_eq!(layout.height_of(3), 40.0);
+        assert_eq!(layout.height_of(4), 48.0);
+    }
+
+    #[test]
+    fn index_at_finds_the_item_under_the_offset() {
+        let mut measured = MeasuredHeights::default();
+        measured.insert(1, 100.0);
+        let layout = Layout {
+            count: 10,
+            estimate: 50.0,
+            measured: &measured,
+        };
+        assert_eq!(layout.index_at(0.0), 0);
+        assert_eq!(layout.index_at(49.0), 0);
+        assert_eq!(layout.index_at(50.0), 1);
+        assert_eq!(layout.index_at(149.0), 1);
+        assert_eq!(layout.index_at(150.0), 2);
+        assert_eq!(layout.index_at(10_000.0), 9);
+        assert_eq!(layout.total(), 550.0);
+    }
+}
@@ -1,12 +1,13 @@
 use std::rc::Rc;
 
-use yew::virtual_dom::{Listener, VTag};
+use yew::html::oncontextmenu;
+use yew::virtual_dom::{AttrValue, Listener, VTag};
 use yew::{Callback, Html};
 
 use crate::dom::existing::JsObjectAccess;
 use crate::dom::{self, JsCast, JsValue};
 use crate::utils::VTagExt;
-use crate::{Element, EventListener};
+use crate::{Density, Element, EventListener, Menu};
 
 pub trait MdcWidget {
     const NAME: &'static str;
@@ -69,6 +70,31 @@ pub trait MdcWidget {
         self
     }
 
+    /// Sets the density scale to the widget and its nested widgets.
+    fn density(mut self, density: impl Into<Density>) -> Self
+    where
+        Self: Sized,
+    {
+        let root = self.root_tag_mut();
+        for class in Density::scale_classes() {
+            root.remove_class(&class);
+        }
+        for class in density.into().classes() {
+            root.add_class_if_needed(class);
+        }
+        self
+    }
+
+    /// Opens the existing menu with the ID at the pointer, when the widget is right-clicked.
+    fn context_menu(self, menu_id: impl Into<AttrValue>) -> Self
+    where
+        Self: Sized,
+    {
+        self.listener(Rc::new(oncontextmenu::Wrapper::new(Menu::open_on_context_menu(
+            menu_id,
+        ))))
+    }
+
     fn child(mut self, child: impl Into<Html>) -> Self
     where
         Self: Sized,